@@ -0,0 +1,89 @@
+use crate::GeoTilerError;
+
+/// Converts a coordinate expressed in degrees, minutes and seconds to decimal degrees.
+///
+/// The result can be passed directly to [`ll_to_cartesian`](crate::ll_to_cartesian). Southern
+/// latitudes and western longitudes are returned as negative values.
+///
+/// # Arguments
+///
+/// * `degrees` - Whole or fractional degrees, non-negative
+/// * `minutes` - Minutes of arc, in the range [0, 60)
+/// * `seconds` - Seconds of arc, in the range [0, 60)
+/// * `hemisphere` - `'N'` or `'S'` for a latitude, `'E'` or `'W'` for a longitude (case-insensitive)
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The coordinate in decimal degrees
+/// * `Err(GeoTilerError::CoordinateFormatError)` - An error if a component is negative, not finite
+///   or out of range, if the hemisphere is unknown, or if the result exceeds 90° for a latitude or
+///   180° for a longitude
+///
+/// # Example
+///
+/// 40°26'46"N is `dms_to_decimal(40.0, 26.0, 46.0, 'N')`, which is about 40.4461.
+pub fn dms_to_decimal(degrees: f64, minutes: f64, seconds: f64, hemisphere: char) -> Result<f64, GeoTilerError> {
+    if !degrees.is_finite() || degrees < 0.0 {
+        return Err(GeoTilerError::CoordinateFormatError(
+            format!("Degrees must be a finite, non-negative value, found {}", degrees)
+        ));
+    }
+
+    if !minutes.is_finite() || !(0.0..60.0).contains(&minutes) {
+        return Err(GeoTilerError::CoordinateFormatError(
+            format!("Minutes must be in the range [0, 60), found {}", minutes)
+        ));
+    }
+
+    if !seconds.is_finite() || !(0.0..60.0).contains(&seconds) {
+        return Err(GeoTilerError::CoordinateFormatError(
+            format!("Seconds must be in the range [0, 60), found {}", seconds)
+        ));
+    }
+
+    let (max_degrees, sign): (f64, f64) = match hemisphere.to_ascii_uppercase() {
+        'N' => (90.0, 1.0),
+        'S' => (90.0, -1.0),
+        'E' => (180.0, 1.0),
+        'W' => (180.0, -1.0),
+        _ => return Err(GeoTilerError::CoordinateFormatError(
+            format!("Hemisphere must be one of 'N', 'S', 'E' or 'W', found '{}'", hemisphere)
+        )),
+    };
+
+    let decimal: f64 = degrees + minutes / 60.0 + seconds / 3600.0;
+    if decimal > max_degrees {
+        return Err(GeoTilerError::CoordinateFormatError(
+            format!("{}°{}'{}\"{} exceeds the maximum of {} degrees", degrees, minutes, seconds, hemisphere, max_degrees)
+        ));
+    }
+
+    Ok(sign * decimal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dms_to_decimal_known_conversions() {
+        assert!((dms_to_decimal(40.0, 26.0, 46.0, 'N').unwrap() - 40.446111).abs() < 1e-6);
+        assert!((dms_to_decimal(79.0, 58.0, 56.0, 'W').unwrap() + 79.982222).abs() < 1e-6);
+        assert_eq!(dms_to_decimal(33.0, 30.0, 0.0, 's').unwrap(), -33.5);
+        assert_eq!(dms_to_decimal(180.0, 0.0, 0.0, 'E').unwrap(), 180.0);
+
+        let latitude: f64 = dms_to_decimal(40.0, 26.0, 46.0, 'N').unwrap();
+        assert!(crate::ll_to_cartesian(0.0, latitude).is_ok());
+    }
+
+    #[test]
+    fn test_dms_to_decimal_rejects_invalid_components() {
+        let is_format_error = |result: Result<f64, GeoTilerError>| matches!(result, Err(GeoTilerError::CoordinateFormatError(_)));
+
+        assert!(is_format_error(dms_to_decimal(-1.0, 0.0, 0.0, 'N')));
+        assert!(is_format_error(dms_to_decimal(10.0, 60.0, 0.0, 'N')));
+        assert!(is_format_error(dms_to_decimal(10.0, 0.0, f64::NAN, 'N')));
+        assert!(is_format_error(dms_to_decimal(90.0, 0.0, 1.0, 'N')));
+        assert!(is_format_error(dms_to_decimal(10.0, 0.0, 0.0, 'X')));
+    }
+}
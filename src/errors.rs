@@ -1,5 +1,7 @@
 use std::fmt;
+use std::io;
 use std::error::Error;
+use std::sync::Arc;
 
 /// Represents errors that can occur in the Geo Tiler library.
 ///
@@ -105,6 +107,64 @@ pub enum GeoTilerError {
     ///
     /// * `0` - Detailed error message
     TriangulationError(String),
+
+    /// Error when a coordinate is given in a malformed notation.
+    ///
+    /// This occurs when converting coordinates from another notation, such as
+    /// degrees-minutes-seconds, with components out of range or an unknown hemisphere.
+    ///
+    /// # Fields
+    ///
+    /// * `0` - Detailed error message
+    CoordinateFormatError(String),
+
+    /// Error when reading or writing data fails.
+    ///
+    /// This wraps an underlying I/O error, which is returned by `Error::source()`.
+    /// The message only describes the context, the cause is not repeated in it.
+    ///
+    /// # Fields
+    ///
+    /// * `message` - Detailed error message
+    /// * `source` - The underlying error, if any
+    IoError {
+        message: String,
+        source: Option<ErrorSource>,
+    },
+
+    /// Error when GeoJSON input cannot be parsed or converted.
+    ///
+    /// This wraps an underlying GeoJSON error, which is returned by `Error::source()`.
+    /// The message only describes the context, the cause is not repeated in it.
+    ///
+    /// # Fields
+    ///
+    /// * `message` - Detailed error message
+    /// * `source` - The underlying error, if any
+    GeoJsonError {
+        message: String,
+        source: Option<ErrorSource>,
+    },
+}
+
+/// Shared handle to the underlying cause of a wrapping `GeoTilerError` variant.
+///
+/// The cause is reference counted so that `GeoTilerError` stays `Clone`. Two sources
+/// compare equal when their messages are equal.
+#[derive(Debug, Clone)]
+pub struct ErrorSource(Arc<dyn Error + Send + Sync + 'static>);
+
+impl ErrorSource {
+    /// Wraps an error so that it can be stored as the source of a `GeoTilerError`.
+    pub fn new<E: Error + Send + Sync + 'static>(error: E) -> Self {
+        ErrorSource(Arc::new(error))
+    }
+}
+
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
 }
 
 impl fmt::Display for GeoTilerError {
@@ -145,8 +205,66 @@ impl fmt::Display for GeoTilerError {
             GeoTilerError::TriangulationError(msg) => {
                 write!(f, "Triangulation error: {}", msg)
             }
+            GeoTilerError::CoordinateFormatError(msg) => {
+                write!(f, "Coordinate format error: {}", msg)
+            }
+            GeoTilerError::IoError { message, .. } => {
+                write!(f, "I/O error: {}", message)
+            }
+            GeoTilerError::GeoJsonError { message, .. } => {
+                write!(f, "GeoJSON error: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for GeoTilerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GeoTilerError::IoError { source: Some(source), .. }
+            | GeoTilerError::GeoJsonError { source: Some(source), .. } => Some(source.0.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GeoTilerError {
+    fn from(error: io::Error) -> Self {
+        GeoTilerError::IoError {
+            message: "Failed to read or write data".to_string(),
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+}
+
+impl From<geojson::Error> for GeoTilerError {
+    fn from(error: geojson::Error) -> Self {
+        GeoTilerError::GeoJsonError {
+            message: "Failed to parse or convert GeoJSON".to_string(),
+            source: Some(ErrorSource::new(error)),
         }
     }
 }
 
-impl Error for GeoTilerError {}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_error_source_is_preserved() {
+        let error: GeoTilerError = io::Error::new(io::ErrorKind::UnexpectedEof, "tile file is truncated").into();
+
+        let source: &(dyn Error + 'static) = error.source().expect("wrapped error should have a source");
+        let io_error: &io::Error = source.downcast_ref::<io::Error>().expect("source should be an io::Error");
+        assert_eq!(io_error.kind(), io::ErrorKind::UnexpectedEof);
+
+        // the cause is reported by source() only, so that error chains do not print it twice
+        assert!(!error.to_string().contains("tile file is truncated"));
+    }
+
+    #[test]
+    fn test_errors_without_cause_have_no_source() {
+        assert!(GeoTilerError::MeshGenerationError("bad ring".to_string()).source().is_none());
+        assert!(GeoTilerError::IoError { message: "count too large".to_string(), source: None }.source().is_none());
+    }
+}
@@ -0,0 +1,602 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use geo::{Coord, LineString, Polygon};
+use crate::{GeoTilerError, PolygonMeshData, Tile};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "image")]
+use crate::{tile::validate_grid_step, ErrorSource};
+#[cfg(feature = "image")]
+use geo::BoundingRect;
+#[cfg(feature = "image")]
+use image::{GrayImage, Luma};
+
+/// Number of decimal places beyond which rounding an `f64` in [-1, 1] has no effect.
+const MAX_ROUNDING_DECIMALS: u32 = 16;
+
+/// Side in pixels of the square drawn for each grid cell by [`render_coverage_png`].
+#[cfg(feature = "image")]
+const COVERAGE_PIXELS_PER_CELL: u32 = 4;
+
+/// Gray levels of the tiles holding fragments and of the empty tiles in coverage images.
+#[cfg(feature = "image")]
+const COVERAGE_POPULATED_SHADE: u8 = 48;
+#[cfg(feature = "image")]
+const COVERAGE_EMPTY_SHADE: u8 = 224;
+
+/// Coordinate system of the vertices of exported meshes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeshProjection {
+    /// Earth-centered Cartesian `(x, y, z)` coordinates, as produced by the mesh generators.
+    /// This is the default.
+    #[default]
+    Geocentric,
+
+    /// Stereographic plane coordinates, as produced by
+    /// [`project_mesh_points`](crate::project_mesh_points), stored as `(x, y, 0)`.
+    Stereographic,
+}
+
+/// Unit of the vertex coordinates of exported meshes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoordinateUnits {
+    /// Coordinates relative to the sphere's radius, so that vertices lie on the unit sphere.
+    /// This is the default, matching the output of the mesh generators.
+    #[default]
+    Normalized,
+
+    /// Coordinates in kilometers.
+    Kilometers,
+
+    /// Coordinates in meters.
+    Meters,
+}
+
+/// Describes how to interpret the vertex coordinates of the meshes in an exported tile file.
+///
+/// Use `MeshMetadata::default()` for the unscaled output of the mesh generators: geocentric
+/// coordinates on the unit sphere.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MeshMetadata {
+    /// Radius of the sphere the vertices were computed on, expressed in `units`
+    pub radius: f64,
+
+    /// Coordinate system of the vertices
+    pub projection: MeshProjection,
+
+    /// Unit of the vertex coordinates
+    pub units: CoordinateUnits,
+}
+
+impl Default for MeshMetadata {
+    fn default() -> Self {
+        MeshMetadata {
+            radius: 1.0,
+            projection: MeshProjection::default(),
+            units: CoordinateUnits::default(),
+        }
+    }
+}
+
+/// Contents of an exported tile file: the meshes of the tile's fragments, along with the metadata
+/// needed to interpret their coordinates.
+///
+/// It serializes to an object with a `metadata` field holding the [`MeshMetadata`] and a `meshes`
+/// field holding the array of meshes, and can be deserialized back with serde. The boundary
+/// vertex indices of the meshes are not serialized, so they are empty after deserialization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TileMeshFile {
+    pub metadata: MeshMetadata,
+    pub meshes: Vec<PolygonMeshData>,
+}
+
+/// Rounds every vertex component of a mesh to the given number of decimal places.
+///
+/// Full `f64` precision makes serialized meshes needlessly large, so this is meant to be applied
+/// right before serialization. Since the vertices lie on the unit sphere, an error of `1e-d`
+/// corresponds to `6371 km × 1e-d` on the Earth's surface: 7 decimals keep the vertices within
+/// about 30 cm of their exact position, and 9 decimals within a few millimeters.
+///
+/// # Arguments
+///
+/// * `mesh` - The mesh whose vertices are rounded in place. Triangles are left untouched.
+/// * `decimals` - Number of decimal places to keep. Values of 16 or more leave the mesh unchanged.
+pub fn round_coordinates(mesh: &mut PolygonMeshData, decimals: u32) {
+    if decimals >= MAX_ROUNDING_DECIMALS {
+        return;
+    }
+
+    let scale: f64 = 10f64.powi(decimals as i32);
+    let round = |value: f64| (value * scale).round() / scale;
+
+    for vertex in mesh.vertices.iter_mut() {
+        *vertex = (round(vertex.0), round(vertex.1), round(vertex.2));
+    }
+}
+
+/// Writes a mesh in the PLY format, either as ASCII or as little-endian binary.
+///
+/// The file holds a `vertex` element with the `double` properties `x`, `y`, `z` and the normal
+/// `nx`, `ny`, `nz` of each vertex, followed by a `face` element with a `list uchar uint
+/// vertex_indices` property per triangle. Since the vertices lie on the unit sphere, the normal of
+/// a vertex is its position normalized, pointing away from the center of the sphere; a vertex at
+/// the origin gets a zero normal.
+///
+/// # Arguments
+///
+/// * `mesh` - The mesh to write
+/// * `w` - The destination writer
+/// * `binary` - Writes `binary_little_endian` PLY if `true`, ASCII PLY otherwise
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::MeshGenerationError` if the number of triangle indices is not a
+/// multiple of 3 or a triangle references a vertex that does not exist, and
+/// `GeoTilerError::IoError` if writing fails.
+pub fn write_ply<W: Write>(mesh: &PolygonMeshData, w: &mut W, binary: bool) -> Result<(), GeoTilerError> {
+    if !mesh.triangles.len().is_multiple_of(3) {
+        return Err(GeoTilerError::MeshGenerationError(
+            format!("Mesh has {} triangle indices, which is not a multiple of 3", mesh.triangles.len())
+        ));
+    }
+    if let Some(index) = mesh.triangles.iter().find(|&&index| index as usize >= mesh.vertices.len()) {
+        return Err(GeoTilerError::MeshGenerationError(
+            format!("Triangle references vertex {} but the mesh has {} vertices", index, mesh.vertices.len())
+        ));
+    }
+
+    let format: &str = if binary { "binary_little_endian" } else { "ascii" };
+    write!(
+        w,
+        "ply\nformat {} 1.0\nelement vertex {}\n\
+        property double x\nproperty double y\nproperty double z\n\
+        property double nx\nproperty double ny\nproperty double nz\n\
+        element face {}\nproperty list uchar uint vertex_indices\nend_header\n",
+        format, mesh.vertices.len(), mesh.triangle_count()
+    )?;
+
+    for &(x, y, z) in &mesh.vertices {
+        let length: f64 = (x * x + y * y + z * z).sqrt();
+        let normal: [f64; 3] = if length > 0.0 { [x / length, y / length, z / length] } else { [0.0; 3] };
+
+        if binary {
+            for value in [x, y, z].iter().chain(&normal) {
+                w.write_all(&value.to_le_bytes())?;
+            }
+        } else {
+            writeln!(w, "{} {} {} {} {} {}", x, y, z, normal[0], normal[1], normal[2])?;
+        }
+    }
+
+    for [a, b, c] in mesh.triangle_iter() {
+        if binary {
+            w.write_all(&[3])?;
+            for index in [a, b, c] {
+                w.write_all(&index.to_le_bytes())?;
+            }
+        } else {
+            writeln!(w, "3 {} {} {}", a, b, c)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a tile to a compact binary format.
+///
+/// The layout is length-prefixed and little-endian:
+/// * a polygon is written as its ring count (`u32`), followed by each ring, exterior first
+/// * a ring is written as its coordinate count (`u32`), followed by each coordinate as two
+///   `f64` values (x then y)
+/// * a tile is written as its `vertices` polygon, followed by its fragment count (`u32`) and
+///   each fragment polygon, and finally its `fragment_tile_ids` count (`u32`) and each recorded
+///   tile index as a `u64`
+///
+/// # Arguments
+///
+/// * `tile` - The tile to write, including its polygon fragments
+/// * `w` - The destination writer
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::IoError` if writing fails or if a count does not fit in a `u32`.
+pub fn write_tile_binary<W: Write>(tile: &Tile, w: &mut W) -> Result<(), GeoTilerError> {
+    write_polygon(&tile.vertices, w)?;
+
+    write_count(tile.polygons.len(), w)?;
+    for polygon in &tile.polygons {
+        write_polygon(polygon, w)?;
+    }
+
+    write_count(tile.fragment_tile_ids.len(), w)?;
+    for &id in &tile.fragment_tile_ids {
+        w.write_all(&(id as u64).to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Reads a tile written by [`write_tile_binary`].
+///
+/// # Arguments
+///
+/// * `r` - The source reader
+///
+/// # Returns
+///
+/// * `Ok(Tile)` - The tile with its boundary, polygon fragments and fragment tile indices
+/// * `Err(GeoTilerError::IoError)` - An error if reading fails, the input is truncated or a tile
+///   index does not fit in a `usize`
+pub fn read_tile_binary<R: Read>(r: &mut R) -> Result<Tile, GeoTilerError> {
+    let vertices: Polygon<f64> = read_polygon(r)?;
+
+    let fragment_count: u32 = read_u32(r)?;
+    let mut polygons: Vec<Polygon<f64>> = Vec::new();
+    for _ in 0..fragment_count {
+        polygons.push(read_polygon(r)?);
+    }
+
+    let id_count: u32 = read_u32(r)?;
+    let mut fragment_tile_ids: Vec<usize> = Vec::new();
+    for _ in 0..id_count {
+        let id: u64 = read_u64(r)?;
+        fragment_tile_ids.push(usize::try_from(id).map_err(|_| GeoTilerError::IoError {
+            message: format!("Tile index {} in binary tile does not fit in a usize", id),
+            source: None,
+        })?);
+    }
+
+    Ok(Tile { vertices, polygons, fragment_tile_ids })
+}
+
+/// Saves the boundaries of a grid's tiles to a file so the grid can be reloaded with
+/// [`load_grid_template`] instead of being regenerated.
+///
+/// Only the tile boundaries are saved, polygon fragments are ignored. The file holds the tile
+/// count (`u32`) followed by each tile boundary, using the polygon layout described in
+/// [`write_tile_binary`].
+///
+/// # Arguments
+///
+/// * `grid` - The tiles whose boundaries are saved
+/// * `path` - The file to create or overwrite
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::IoError` if the file cannot be created or written.
+pub fn save_grid_template(grid: &[Tile], path: &Path) -> Result<(), GeoTilerError> {
+    let mut w: BufWriter<File> = BufWriter::new(File::create(path)?);
+
+    write_count(grid.len(), &mut w)?;
+    for tile in grid {
+        write_polygon(&tile.vertices, &mut w)?;
+    }
+
+    w.flush()?;
+
+    Ok(())
+}
+
+/// Loads a grid saved by [`save_grid_template`].
+///
+/// # Arguments
+///
+/// * `path` - The file to read
+///
+/// # Returns
+///
+/// * `Ok(Vec<Tile>)` - The tiles, in the order they were saved, with no polygon fragments
+/// * `Err(GeoTilerError::IoError)` - An error if the file cannot be read or is truncated
+pub fn load_grid_template(path: &Path) -> Result<Vec<Tile>, GeoTilerError> {
+    let mut r: BufReader<File> = BufReader::new(File::open(path)?);
+
+    let tile_count: u32 = read_u32(&mut r)?;
+    let mut grid: Vec<Tile> = Vec::new();
+    for _ in 0..tile_count {
+        grid.push(Tile::new(read_polygon(&mut r)?));
+    }
+
+    Ok(grid)
+}
+
+/// Renders which tiles of a grid hold polygon fragments to a grayscale PNG image.
+///
+/// This is a diagnostic aid for checking at a glance which tiles a dataset reached, not a
+/// rendering of the fragments themselves. The image is an equirectangular raster, north up and
+/// west on the left, where each `step` × `step` degree cell is a square of 4 × 4 pixels, so it
+/// is `4 × 360 / step` pixels wide and `4 × 180 / step` pixels high. Every tile holding at least
+/// one fragment is shaded dark over its extent, and the rest of the image is left light.
+///
+/// Only available with the `image` feature.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles to render. Each tile is drawn over its bounding box, so grids with
+///   missing tiles or polar caps are rendered as well.
+/// * `step` - The angular step size in degrees the grid was generated with
+/// * `path` - The PNG file to create or overwrite
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` if `step` is invalid, under the same conditions
+/// as [`generate_grid`](crate::generate_grid), and `GeoTilerError::IoError` if the image cannot
+/// be encoded or written.
+#[cfg(feature = "image")]
+pub fn render_coverage_png(grid: &[Tile], step: usize, path: &Path) -> Result<(), GeoTilerError> {
+    validate_grid_step(step)?;
+
+    let pixels_per_degree: f64 = COVERAGE_PIXELS_PER_CELL as f64 / step as f64;
+    let width: u32 = (360 / step) as u32 * COVERAGE_PIXELS_PER_CELL;
+    let height: u32 = (180 / step) as u32 * COVERAGE_PIXELS_PER_CELL;
+
+    // pixel columns count from the antimeridian eastward and pixel rows from the north pole southward
+    let to_pixel = |degrees: f64, limit: u32| -> u32 { ((degrees * pixels_per_degree).round().max(0.0) as u32).min(limit) };
+
+    let mut image: GrayImage = GrayImage::from_pixel(width, height, Luma([COVERAGE_EMPTY_SHADE]));
+    for tile in grid.iter().filter(|tile| !tile.polygons.is_empty()) {
+        let Some(extent) = tile.vertices.bounding_rect() else { continue };
+
+        let (left, right) = (to_pixel(extent.min().x + 180.0, width), to_pixel(extent.max().x + 180.0, width));
+        let (top, bottom) = (to_pixel(90.0 - extent.max().y, height), to_pixel(90.0 - extent.min().y, height));
+        for x in left..right {
+            for y in top..bottom {
+                image.put_pixel(x, y, Luma([COVERAGE_POPULATED_SHADE]));
+            }
+        }
+    }
+
+    image.save(path).map_err(|error| GeoTilerError::IoError {
+        message: format!("Failed to write coverage image to {}", path.display()),
+        source: Some(ErrorSource::new(error)),
+    })
+}
+
+fn write_polygon<W: Write>(polygon: &Polygon<f64>, w: &mut W) -> Result<(), GeoTilerError> {
+    write_count(1 + polygon.interiors().len(), w)?;
+
+    write_ring(polygon.exterior(), w)?;
+    for interior in polygon.interiors() {
+        write_ring(interior, w)?;
+    }
+
+    Ok(())
+}
+
+fn write_ring<W: Write>(ring: &LineString<f64>, w: &mut W) -> Result<(), GeoTilerError> {
+    write_count(ring.0.len(), w)?;
+
+    for coord in ring.coords() {
+        w.write_all(&coord.x.to_le_bytes())?;
+        w.write_all(&coord.y.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn write_count<W: Write>(count: usize, w: &mut W) -> Result<(), GeoTilerError> {
+    let count: u32 = u32::try_from(count).map_err(|_| GeoTilerError::IoError {
+        message: format!("Count {} is too large for the binary tile format", count),
+        source: None,
+    })?;
+    w.write_all(&count.to_le_bytes())?;
+
+    Ok(())
+}
+
+fn read_polygon<R: Read>(r: &mut R) -> Result<Polygon<f64>, GeoTilerError> {
+    let ring_count: u32 = read_u32(r)?;
+    if ring_count == 0 {
+        return Err(GeoTilerError::IoError {
+            message: "Polygon in binary tile has no exterior ring".to_string(),
+            source: None,
+        });
+    }
+
+    let exterior: LineString<f64> = read_ring(r)?;
+    let mut interiors: Vec<LineString<f64>> = Vec::new();
+    for _ in 1..ring_count {
+        interiors.push(read_ring(r)?);
+    }
+
+    Ok(Polygon::new(exterior, interiors))
+}
+
+fn read_ring<R: Read>(r: &mut R) -> Result<LineString<f64>, GeoTilerError> {
+    let coord_count: u32 = read_u32(r)?;
+
+    let mut coords: Vec<Coord<f64>> = Vec::new();
+    for _ in 0..coord_count {
+        let x: f64 = read_f64(r)?;
+        let y: f64 = read_f64(r)?;
+        coords.push(Coord { x, y });
+    }
+
+    Ok(LineString::new(coords))
+}
+
+fn read_u32<R: Read>(r: &mut R) -> Result<u32, GeoTilerError> {
+    let mut bytes: [u8; 4] = [0; 4];
+    r.read_exact(&mut bytes)?;
+
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(r: &mut R) -> Result<u64, GeoTilerError> {
+    let mut bytes: [u8; 8] = [0; 8];
+    r.read_exact(&mut bytes)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_f64<R: Read>(r: &mut R) -> Result<f64, GeoTilerError> {
+    let mut bytes: [u8; 8] = [0; 8];
+    r.read_exact(&mut bytes)?;
+
+    Ok(f64::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::polygon;
+
+    #[test]
+    fn test_tile_binary_round_trip() {
+        let mut tile: Tile = Tile::new(polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 20.0, y: 20.0), (x: 0.0, y: 20.0)]);
+        tile.polygons.push(polygon![(x: 1.0, y: 1.0), (x: 5.5, y: 1.0), (x: 5.5, y: 4.25), (x: 1.0, y: 4.25)]);
+        tile.polygons.push(Polygon::new(
+            LineString::from(vec![(10.0, 10.0), (19.0, 10.0), (19.0, 19.0), (10.0, 19.0), (10.0, 10.0)]),
+            vec![LineString::from(vec![(12.0, 12.0), (14.0, 12.0), (14.0, 14.0), (12.0, 12.0)])],
+        ));
+        tile.fragment_tile_ids = vec![7, 7];
+
+        let mut buffer: Vec<u8> = Vec::new();
+        write_tile_binary(&tile, &mut buffer).unwrap();
+        let read: Tile = read_tile_binary(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(read, tile);
+
+        // a truncated buffer is reported instead of producing a partial tile
+        assert!(matches!(read_tile_binary(&mut &buffer[..buffer.len() - 1]), Err(GeoTilerError::IoError { .. })));
+    }
+
+
+    #[test]
+    fn test_round_coordinates_to_three_decimals() {
+        let mut mesh: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(0.5773502691896258, -0.12345678, 0.9999996)],
+            triangles: vec![0, 0, 0],
+            boundary_indices: vec![0],
+        };
+
+        round_coordinates(&mut mesh, 3);
+        assert_eq!(mesh.vertices, vec![(0.577, -0.123, 1.0)]);
+        assert_eq!(mesh.triangles, vec![0, 0, 0]);
+
+        let original: (f64, f64, f64) = (0.5773502691896258, -0.12345678, 0.9999996);
+        let mut unchanged: PolygonMeshData = PolygonMeshData { vertices: vec![original], ..mesh };
+        round_coordinates(&mut unchanged, 16);
+        assert_eq!(unchanged.vertices, vec![original]);
+    }
+
+
+    #[test]
+    fn test_grid_template_round_trip() {
+        let mut grid: Vec<Tile> = crate::generate_grid(10).unwrap();
+        grid[0].polygons.push(polygon![(x: -179.0, y: -89.0), (x: -175.0, y: -89.0), (x: -175.0, y: -85.0)]);
+
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!("geo_tiler_template_{}.bin", std::process::id()));
+        save_grid_template(&grid, &path).unwrap();
+        let loaded: Result<Vec<Tile>, GeoTilerError> = load_grid_template(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded: Vec<Tile> = loaded.unwrap();
+
+        let expected: Vec<Tile> = crate::generate_grid(10).unwrap();
+        assert_eq!(loaded.len(), expected.len());
+        for (loaded_tile, expected_tile) in loaded.iter().zip(&expected) {
+            assert_eq!(loaded_tile.vertices, expected_tile.vertices);
+            assert!(loaded_tile.polygons.is_empty());
+        }
+
+        assert!(load_grid_template(&path).is_err());
+    }
+
+    #[test]
+    fn test_clipped_tiles_binary_round_trip_keeps_fragment_tile_ids() {
+        let mut grid: Vec<Tile> = crate::generate_grid(20).unwrap();
+        let polygon: Polygon<f64> = polygon![(x: -15.0, y: -5.0), (x: 15.0, y: -5.0), (x: 15.0, y: 5.0), (x: -15.0, y: 5.0)];
+        crate::clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        let populated: Vec<&Tile> = grid.iter().filter(|tile| !tile.polygons.is_empty()).collect();
+        assert!(populated.len() > 1);
+        for tile in populated {
+            assert!(!tile.fragment_tile_ids.is_empty());
+
+            let mut buffer: Vec<u8> = Vec::new();
+            write_tile_binary(tile, &mut buffer).unwrap();
+            assert_eq!(&read_tile_binary(&mut buffer.as_slice()).unwrap(), tile);
+        }
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_render_coverage_png_shades_populated_tiles() {
+        let mut grid: Vec<Tile> = crate::generate_grid(20).unwrap();
+        crate::clip_polygon_to_tiles(&mut grid, &polygon![(x: 5.0, y: -5.0), (x: 15.0, y: -5.0), (x: 15.0, y: 5.0), (x: 5.0, y: 5.0)]).unwrap();
+
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!("geo_tiler_coverage_{}.png", std::process::id()));
+        render_coverage_png(&grid, 20, &path).unwrap();
+        let size: u64 = std::fs::metadata(&path).unwrap().len();
+        let image: Result<image::DynamicImage, image::ImageError> = image::open(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(size > 0);
+        let image: GrayImage = image.unwrap().to_luma8();
+        assert_eq!(image.dimensions(), (4 * 360 / 20, 4 * 180 / 20));
+
+        // the tile from (0, -10) to (20, 10) covers pixel columns 36..40 and rows 16..20
+        assert_eq!(image.get_pixel(37, 17), &Luma([COVERAGE_POPULATED_SHADE]));
+        assert_eq!(image.get_pixel(41, 17), &Luma([COVERAGE_EMPTY_SHADE]));
+        assert_eq!(image.get_pixel(0, 0), &Luma([COVERAGE_EMPTY_SHADE]));
+    }
+
+    #[test]
+    fn test_write_ply_ascii_and_binary() {
+        let mesh: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(1.0, 0.0, 0.0), (0.0, 2.0, 0.0), (0.0, 0.0, 1.0), (0.0, 0.0, 0.0)],
+            triangles: vec![0, 1, 2, 0, 2, 3],
+            boundary_indices: vec![0, 1, 2],
+        };
+
+        let mut ascii: Vec<u8> = Vec::new();
+        write_ply(&mesh, &mut ascii, false).unwrap();
+        let ascii: String = String::from_utf8(ascii).unwrap();
+        let (header, body) = ascii.split_once("end_header\n").unwrap();
+        assert!(header.starts_with("ply\nformat ascii 1.0\n"));
+        assert!(header.contains("element vertex 4\n"));
+        assert!(header.contains("property double nz\n"));
+        assert!(header.contains("element face 2\nproperty list uchar uint vertex_indices\n"));
+
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 4 + 2);
+        assert_eq!(lines[1], "0 2 0 0 1 0");
+        assert_eq!(lines[3], "0 0 0 0 0 0");
+        assert_eq!(lines[5], "3 0 2 3");
+
+        let mut binary: Vec<u8> = Vec::new();
+        write_ply(&mesh, &mut binary, true).unwrap();
+        let header_length: usize = header.replace("ascii", "binary_little_endian").len() + "end_header\n".len();
+        assert!(binary.starts_with(b"ply\nformat binary_little_endian 1.0\n"));
+        assert_eq!(binary.len(), header_length + 4 * 6 * 8 + 2 * (1 + 3 * 4));
+        assert_eq!(binary[binary.len() - 13], 3);
+
+        let invalid: PolygonMeshData = PolygonMeshData { triangles: vec![0, 1, 4], ..mesh };
+        assert!(matches!(write_ply(&invalid, &mut Vec::new(), false), Err(GeoTilerError::MeshGenerationError(_))));
+    }
+
+    #[test]
+    fn test_tile_mesh_file_serde_round_trip() {
+        let file: TileMeshFile = TileMeshFile {
+            metadata: MeshMetadata { radius: 6371.0, projection: MeshProjection::Stereographic, units: CoordinateUnits::Kilometers },
+            meshes: vec![PolygonMeshData {
+                vertices: vec![(0.1, 0.2, 0.0), (1.0 / 3.0, -0.7, 0.0), (-0.5, 0.25, 0.0)],
+                triangles: vec![0, 1, 2],
+                boundary_indices: Vec::new(),
+            }],
+        };
+
+        let json: String = serde_json::to_string(&file).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["metadata"]["projection"], "stereographic");
+        assert_eq!(value["metadata"]["units"], "kilometers");
+        assert_eq!(value["meshes"].as_array().unwrap().len(), 1);
+
+        let read: TileMeshFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, file);
+
+        let default: String = serde_json::to_string(&MeshMetadata::default()).unwrap();
+        assert_eq!(default, r#"{"radius":1.0,"projection":"geocentric","units":"normalized"}"#);
+    }
+}
@@ -17,13 +17,44 @@ use geo::{coord, Coord};
 ///
 /// # Errors
 ///
-/// Returns error if `n` is 0 or if division by zero would occur.
+/// Returns error if `n` is 0, if division by zero would occur, or if a point ends up with a
+/// non-finite coordinate. The height of each point is clamped to [-1, 1] before computing its
+/// latitude, so rounding errors cannot produce a NaN latitude.
 pub fn fibonacci_sphere(n: usize) -> Result<Vec<Coord<f64>>, GeoTilerError> {
+    fibonacci_sphere_with_angle(n, PI * (5.0_f64.sqrt() - 1.0))
+}
+
+/// Generates points on a unit sphere along a spiral with a custom angle between consecutive points.
+///
+/// This is [`fibonacci_sphere`] with the golden angle `π(√5 - 1)` replaced by `golden_angle`,
+/// which is useful to study how the angle affects the quality of the distribution. Angles that
+/// are rational multiples of 2π place the points on a few meridians instead of spreading them
+/// evenly.
+///
+/// # Arguments
+///
+/// * `n` - Number of points to generate (must be > 0)
+/// * `golden_angle` - Longitude increment in radians between consecutive points
+///
+/// # Returns
+///
+/// * `Result<Vec<Coord<f64>>, GeoTilerError>` - Vector of longitude and latitude coordinates
+///   in radians, with longitude in range [-π, π] and latitude in range [-π/2, π/2].
+///
+/// # Errors
+///
+/// Returns error under the same conditions as [`fibonacci_sphere`], or if `golden_angle` is not
+/// a finite number.
+pub fn fibonacci_sphere_with_angle(n: usize, golden_angle: f64) -> Result<Vec<Coord<f64>>, GeoTilerError> {
     if n == 0 {
         return Err(GeoTilerError::FibonacciError("Cannot generate zero points in fibonacci sphere".to_string()));
     }
+
+    if !golden_angle.is_finite() {
+        return Err(GeoTilerError::FibonacciError(format!("Spiral angle must be finite, got {}", golden_angle)));
+    }
     
-    let phi: f64 = PI * (5.0_f64.sqrt() - 1.0);
+    let phi: f64 = golden_angle;
     let mut points: Vec<Coord<f64>> = Vec::with_capacity(n);
     let denominator: f64 = if n > 1 { n as f64 - 1.0 } else { 1.0 };
     
@@ -35,17 +66,169 @@ pub fn fibonacci_sphere(n: usize) -> Result<Vec<Coord<f64>>, GeoTilerError> {
         let y: f64 = 1.0 - (i as f64 / denominator) * 2.0;
         let theta: f64 = phi * i as f64;
         
-        let mut longitude: f64 = theta % (2.0 * PI); // Keep longitude in [0, 2π]
+        let mut longitude: f64 = theta.rem_euclid(2.0 * PI); // Keep longitude in [0, 2π]
         
         // Convert to range [-π, π]
         if longitude > PI {
             longitude -= 2.0 * PI;
         }
         
-        let latitude: f64 = y.asin(); // Already in radians
+        // rounding can push y slightly outside [-1, 1], where asin would return NaN
+        let latitude: f64 = y.clamp(-1.0, 1.0).asin(); // Already in radians
+
+        if !longitude.is_finite() || !latitude.is_finite() {
+            return Err(GeoTilerError::FibonacciError(
+                format!("Point {} of {} has a non-finite coordinate ({}, {})", i, n, longitude, latitude)
+            ));
+        }
         
         points.push(coord! {x: longitude, y: latitude});
     }
     
     Ok(points)
-}
\ No newline at end of file
+}
+
+/// Generates Fibonacci sphere points with a small, deterministic pseudo-random offset applied
+/// to each point.
+///
+/// This is used to perturb a point distribution when an exact Fibonacci lattice produces a
+/// degenerate configuration (e.g. nearly coincident points after projection). The same `seed`
+/// always produces the same points.
+///
+/// # Arguments
+///
+/// * `n` - Number of points to generate (must be > 0)
+/// * `seed` - Seed of the pseudo-random offsets
+/// * `jitter` - Maximum offset of each coordinate, as a fraction of the average spacing
+///   between points. A value of 0 reproduces `fibonacci_sphere(n)`.
+///
+/// # Returns
+///
+/// * `Result<Vec<Coord<f64>>, GeoTilerError>` - Vector of longitude and latitude coordinates
+///   in radians, with longitude in range [-π, π] and latitude in range [-π/2, π/2].
+///
+/// # Errors
+///
+/// Returns error if `n` is 0.
+pub fn fibonacci_sphere_jittered(n: usize, seed: u64, jitter: f64) -> Result<Vec<Coord<f64>>, GeoTilerError> {
+    let mut points: Vec<Coord<f64>> = fibonacci_sphere(n)?;
+
+    // average angular distance between neighbouring points on the unit sphere
+    let spacing: f64 = (4.0 * PI / n as f64).sqrt();
+    let amplitude: f64 = jitter * spacing;
+
+    for (i, point) in points.iter_mut().enumerate() {
+        let mut state: u64 = seed ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let dx: f64 = unit_random(&mut state) * amplitude;
+        let dy: f64 = unit_random(&mut state) * amplitude;
+
+        let mut longitude: f64 = point.x + dx;
+        if longitude > PI {
+            longitude -= 2.0 * PI;
+        } else if longitude < -PI {
+            longitude += 2.0 * PI;
+        }
+
+        point.x = longitude;
+        point.y = (point.y + dy).clamp(-PI / 2.0, PI / 2.0);
+    }
+
+    Ok(points)
+}
+
+/// Computes the number of Fibonacci sphere points giving a target density of points per steradian.
+///
+/// Fibonacci points are spread almost uniformly over the sphere's 4π steradians, so a polygon
+/// covering a solid angle `Ω` receives on average `n · Ω / 4π` of the `n` points. The returned
+/// count is therefore `⌈4π · points_per_steradian⌉`. This is an average: the actual number of
+/// points falling inside a given polygon varies by a few points with its shape and position, and
+/// the relative error grows for polygons that only receive a handful of points.
+///
+/// # Arguments
+///
+/// * `points_per_steradian` - The desired average number of points per steradian
+///
+/// # Returns
+///
+/// * `usize` - The total number of points to pass to [`fibonacci_sphere`], at least 1. A density
+///   that is not a positive finite number yields 1.
+pub fn fibonacci_count_for_density(points_per_steradian: f64) -> usize {
+    if !points_per_steradian.is_finite() || points_per_steradian <= 0.0 {
+        return 1;
+    }
+
+    let count: f64 = (4.0 * PI * points_per_steradian).ceil();
+
+    (count as usize).max(1)
+}
+
+/// Returns a pseudo-random value in [-1, 1] using the SplitMix64 generator.
+fn unit_random(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z: u64 = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    (z >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci_count_for_density() {
+        let count: usize = fibonacci_count_for_density(500.0);
+        let doubled: usize = fibonacci_count_for_density(1000.0);
+        assert_eq!(count, (4.0 * PI * 500.0).ceil() as usize);
+        assert!((doubled as f64 / count as f64 - 2.0).abs() < 1e-3);
+
+        // the cap north of 60° receives about the requested density
+        let cap_solid_angle: f64 = 2.0 * PI * (1.0 - 60f64.to_radians().sin());
+        let cap_points: usize = fibonacci_sphere(doubled).unwrap().iter()
+            .filter(|point| point.y > 60f64.to_radians())
+            .count();
+        assert!((cap_points as f64 / cap_solid_angle - 1000.0).abs() < 1000.0 * 0.02);
+
+        assert_eq!(fibonacci_count_for_density(0.0), 1);
+        assert_eq!(fibonacci_count_for_density(f64::NAN), 1);
+    }
+
+    #[test]
+    fn test_fibonacci_sphere_latitudes_are_finite_and_in_range() {
+        for n in [1, 2, 1_000_003] {
+            let points: Vec<Coord<f64>> = fibonacci_sphere(n).unwrap();
+            assert_eq!(points.len(), n);
+            for point in &points {
+                assert!(point.x.is_finite() && point.y.is_finite());
+                assert!((-PI / 2.0..=PI / 2.0).contains(&point.y));
+                assert!((-PI..=PI).contains(&point.x));
+            }
+        }
+
+        assert!(matches!(fibonacci_sphere(0), Err(GeoTilerError::FibonacciError(_))));
+    }
+
+    #[test]
+    fn test_fibonacci_sphere_with_angle() {
+        let default: Vec<Coord<f64>> = fibonacci_sphere(500).unwrap();
+        assert_eq!(fibonacci_sphere_with_angle(500, PI * (5.0_f64.sqrt() - 1.0)).unwrap(), default);
+
+        for angle in [1.0, -2.5] {
+            let custom: Vec<Coord<f64>> = fibonacci_sphere_with_angle(500, angle).unwrap();
+            assert_eq!(custom.len(), default.len());
+            assert_ne!(custom, default);
+            for point in &custom {
+                assert!((-PI..=PI).contains(&point.x));
+                assert!((-PI / 2.0..=PI / 2.0).contains(&point.y));
+            }
+        }
+
+        // half a turn per point puts every point on one of two meridians
+        let meridians: Vec<Coord<f64>> = fibonacci_sphere_with_angle(100, PI).unwrap();
+        assert!(meridians.iter().all(|point| point.x.abs() < 1e-9 || (point.x.abs() - PI).abs() < 1e-9));
+
+        assert!(matches!(fibonacci_sphere_with_angle(10, f64::NAN), Err(GeoTilerError::FibonacciError(_))));
+    }
+}
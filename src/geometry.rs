@@ -2,7 +2,7 @@ use std::f64::consts::PI;
 use std::f64::EPSILON;
 use crate::GeoTilerError;
 use nalgebra::{Rotation, Rotation3, Unit, Vector3};
-use geo::{coord, Coord, Polygon};
+use geo::{coord, BoundingRect, Coord, LineString, Polygon};
 
 /// Converts geographic coordinates (longitude and latitude) from decimal degrees to 3D Cartesian coordinates
 /// on a unit sphere.
@@ -31,13 +31,36 @@ use geo::{coord, Coord, Polygon};
 ///
 /// Where latitude_rad = latitude * π/180 and longitude_rad = longitude * π/180
 pub fn ll_to_cartesian(longitude: f64, latitude: f64) -> Result<(f64, f64, f64), GeoTilerError> {
+    ll_to_cartesian_with_tolerance(longitude, latitude, COORDINATE_RANGE_TOLERANCE)
+}
+
+/// Converts geographic coordinates from decimal degrees to 3D Cartesian coordinates on a unit
+/// sphere, allowing the given slack beyond the valid longitude and latitude ranges.
+///
+/// This behaves like [`ll_to_cartesian`], which allows 0.1° of slack, but suits datasets with
+/// more floating point drift. Latitudes within the slack beyond ±90° are treated as the pole
+/// itself, while longitudes beyond ±180° simply wrap around.
+///
+/// # Arguments
+///
+/// * `longitude` - The longitude in decimal degrees (-180 to 180)
+/// * `latitude` - The latitude in decimal degrees (-90 to 90)
+/// * `tolerance` - Slack in degrees allowed beyond both ranges. Negative values are treated as 0.
+///
+/// # Returns
+///
+/// * `Ok((f64, f64, f64))` - A tuple of (x, y, z) Cartesian coordinates on the unit sphere
+/// * `Err(GeoTilerError::CoordinateRangeError)` - An error if the longitude or latitude values are
+///   outside their valid ranges by more than `tolerance`
+pub fn ll_to_cartesian_with_tolerance(longitude: f64, latitude: f64, tolerance: f64) -> Result<(f64, f64, f64), GeoTilerError> {
 
-    if longitude.abs() > (180.0 + 0.1)  || latitude.abs() > (90.0 + 0.1) { // return error if data is outside of reasonable floating point error
+    if !is_coordinate_in_range(longitude, latitude, tolerance.max(0.0)) { // return error if data is outside of reasonable floating point error
         return Err(GeoTilerError::CoordinateRangeError { longitude, latitude });
     }
 
     let epsilon: f64 = 1e-10;
     let (longitude, latitude) = sanitize_coordinates(longitude, latitude, epsilon);
+    let latitude: f64 = latitude.clamp(-90.0, 90.0);
 
     let longitude_rad: f64 = longitude * PI / 180.0;
     let latitude_rad: f64 = latitude * PI / 180.0;
@@ -50,6 +73,291 @@ pub fn ll_to_cartesian(longitude: f64, latitude: f64) -> Result<(f64, f64, f64),
 }  
 
 
+/// Converts 3D Cartesian coordinates to geographic coordinates (longitude and latitude) in decimal
+/// degrees.
+///
+/// This is the inverse of [`ll_to_cartesian`]. The point does not need to lie exactly on the unit
+/// sphere, it is normalized first so only its direction matters.
+///
+/// # Arguments
+///
+/// * `point` - A 3D point (x, y, z), typically on the unit sphere
+///
+/// # Returns
+///
+/// * `Ok((f64, f64))` - A tuple of (longitude, latitude) in decimal degrees, with longitude in
+///   [-180, 180] and latitude in [-90, 90]
+/// * `Err(GeoTilerError::CoordinateFormatError)` - An error if a component is not finite or the
+///   point is at the origin, where the direction is undefined
+///
+/// # Mathematical formula
+///
+/// For a point (x, y, z) at distance r from the origin:
+/// * longitude = atan2(y, x)
+/// * latitude = asin(z / r)
+pub fn cartesian_to_ll(point: (f64, f64, f64)) -> Result<(f64, f64), GeoTilerError> {
+    let (x, y, z) = point;
+
+    if !x.is_finite() || !y.is_finite() || !z.is_finite() {
+        return Err(GeoTilerError::CoordinateFormatError(
+            format!("Cartesian point ({}, {}, {}) is not finite", x, y, z)
+        ));
+    }
+
+    let radius: f64 = (x * x + y * y + z * z).sqrt();
+    if radius < f64::EPSILON {
+        return Err(GeoTilerError::CoordinateFormatError(
+            "Cartesian point is at the origin and has no geographic direction".to_string()
+        ));
+    }
+
+    let longitude: f64 = y.atan2(x).to_degrees();
+    let latitude: f64 = (z / radius).clamp(-1.0, 1.0).asin().to_degrees();
+
+    Ok((longitude, latitude))
+}
+
+/// Validates every coordinate of a polygon, in all of its rings, in a single pass.
+///
+/// Unlike [`ll_to_cartesian`], which stops at the first bad coordinate, this function collects
+/// every non-finite or out-of-range coordinate so that they can all be fixed at once. Ring 0 is
+/// the exterior ring and rings 1 and above are the interior rings, in order.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `Ok(())` - If every coordinate is finite, with longitude in [-180, 180] and latitude in
+///   [-90, 90] (allowing the same small floating point slack as [`ll_to_cartesian`])
+/// * `Err(GeoTilerError::InvalidPolygonError)` - An error listing each invalid coordinate along
+///   with its ring and index
+pub fn validate_polygon_coordinates(polygon: &Polygon) -> Result<(), GeoTilerError> {
+    let mut problems: Vec<String> = Vec::new();
+
+    let rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+    for (ring_index, ring) in rings.enumerate() {
+        for (coord_index, coord) in ring.coords().enumerate() {
+            if !coord.x.is_finite() || !coord.y.is_finite() {
+                problems.push(format!("ring {} index {}: ({}, {}) is not finite", ring_index, coord_index, coord.x, coord.y));
+            } else if !is_coordinate_in_range(coord.x, coord.y, COORDINATE_RANGE_TOLERANCE) {
+                problems.push(format!("ring {} index {}: ({}, {}) is out of range", ring_index, coord_index, coord.x, coord.y));
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    Err(GeoTilerError::InvalidPolygonError(
+        format!("Polygon contains {} invalid coordinate(s): {}", problems.len(), problems.join("; "))
+    ))
+}
+
+/// Closes every ring of a polygon whose first and last coordinates differ by appending its first
+/// coordinate.
+///
+/// `geo::Polygon` closes its rings when it is constructed or mutated through its own methods, so
+/// polygons built from GeoJSON with unclosed rings are already closed by the time they reach
+/// [`clip_polygon_to_tiles`](crate::clip_polygon_to_tiles) or the mesh generators. This function
+/// makes that guarantee explicit for polygons coming from other sources, and both of those paths
+/// apply it to a copy of their input before validating it. Closed and empty rings are left
+/// unchanged.
+///
+/// # Arguments
+///
+/// * `polygon` - The polygon whose exterior and interior rings are closed in place
+pub fn ensure_closed_ring(polygon: &mut Polygon) {
+    if !polygon.exterior().is_closed() {
+        polygon.exterior_mut(|exterior| exterior.close());
+    }
+
+    if polygon.interiors().iter().any(|interior| !interior.is_closed()) {
+        polygon.interiors_mut(|interiors| {
+            for interior in interiors {
+                interior.close();
+            }
+        });
+    }
+}
+
+/// Returns a copy of a polygon whose exterior is its ring enclosing the largest area.
+///
+/// GeoJSON guarantees that the first ring of a polygon is its exterior, but a `geo::Polygon`
+/// built by hand may hold its outer boundary as an interior by mistake, which makes the meshing
+/// functions fill the wrong region. When an interior ring encloses a larger planar area than the
+/// exterior, as computed with the shoelace formula, the two are swapped and the former exterior
+/// becomes an interior. The order of the other interiors and the winding of every ring are kept.
+///
+/// # Arguments
+///
+/// * `polygon` - A polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `Polygon` - The polygon with its largest ring as the exterior, or an unchanged copy if the
+///   exterior already is the largest ring
+pub fn ensure_exterior_is_largest(polygon: &Polygon) -> Polygon {
+    let exterior_area: f64 = ring_area(polygon.exterior());
+    let largest: Option<(usize, f64)> = polygon.interiors().iter()
+        .map(ring_area)
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+
+    match largest {
+        Some((index, area)) if area > exterior_area => {
+            let mut interiors: Vec<LineString<f64>> = polygon.interiors().to_vec();
+            let exterior: LineString<f64> = std::mem::replace(&mut interiors[index], polygon.exterior().clone());
+
+            Polygon::new(exterior, interiors)
+        }
+        _ => polygon.clone(),
+    }
+}
+
+/// Computes the area of a polygon in square degrees, treating longitude and latitude as planar
+/// coordinates.
+///
+/// The area is obtained with the shoelace formula: the area of the exterior ring minus the areas
+/// of the interior rings, regardless of their winding. It ignores the curvature of the sphere and
+/// the shrinking of longitude degrees towards the poles, which makes it cheap enough to compare
+/// fragments of a tile against a threshold, for instance to discard slivers left by clipping.
+///
+/// # Arguments
+///
+/// * `polygon` - A polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `f64` - The non-negative area of the polygon in square degrees
+pub fn planar_polygon_area(polygon: &Polygon<f64>) -> f64 {
+    let holes_area: f64 = polygon.interiors().iter().map(ring_area).sum();
+
+    (ring_area(polygon.exterior()) - holes_area).max(0.0)
+}
+
+/// Checks whether a polygon encloses no area, such as a polygon whose points are all collinear.
+///
+/// Such polygons pass the vertex count checks but cannot be triangulated, so they are rejected by
+/// the clipping and meshing functions. The area is the one computed by [`planar_polygon_area`],
+/// compared against a tolerance of 1e-12 square degrees to absorb floating point noise.
+///
+/// # Arguments
+///
+/// * `polygon` - A polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `bool` - `true` if the polygon's area is at most 1e-12 square degrees
+pub fn has_zero_area(polygon: &Polygon<f64>) -> bool {
+    planar_polygon_area(polygon) <= ZERO_AREA_EPSILON
+}
+
+/// Computes the angular extent of a polygon, such as a tile fragment, from its bounding box.
+///
+/// This is a cheap measure of size, complementing [`planar_polygon_area`], for choosing a level
+/// of detail. Like the area, it is measured in longitude/latitude space, so a longitude span
+/// covers less ground at high latitudes.
+///
+/// # Arguments
+///
+/// * `polygon` - A polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `(f64, f64)` - The longitude span and the latitude span of the polygon in degrees, or
+///   `(0.0, 0.0)` for an empty polygon
+pub fn fragment_angular_extent(polygon: &Polygon<f64>) -> (f64, f64) {
+    match polygon.bounding_rect() {
+        Some(extent) => (extent.width(), extent.height()),
+        None => (0.0, 0.0),
+    }
+}
+
+/// Checks whether a polygon covers more than a hemisphere of the unit sphere.
+///
+/// The mesh generators rotate a polygon to the south pole and project it stereographically from
+/// the north pole, which distorts polygons covering more than a hemisphere to the point of making
+/// their triangulation unreliable. Such polygons should be split, for instance by clipping them to
+/// a grid, before meshing.
+///
+/// The spherical area is computed like in lon/lat space, with edges following straight lines in
+/// longitude and latitude rather than great circles, so that it is exact for tiles and fragments
+/// bounded by meridians and parallels. The polygon exceeds a hemisphere when that area, exterior
+/// minus holes, is larger than 2π steradians.
+///
+/// # Arguments
+///
+/// * `polygon` - A polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `bool` - `true` if the polygon covers more than 2π steradians, `false` otherwise, including
+///   for polygons with non-finite coordinates
+pub fn polygon_exceeds_hemisphere(polygon: &Polygon<f64>) -> bool {
+    let holes_area: f64 = polygon.interiors().iter().map(spherical_ring_area).sum();
+
+    spherical_ring_area(polygon.exterior()) - holes_area > 2.0 * PI
+}
+
+/// Computes the length of a polygon's boundary along great circles on a sphere.
+///
+/// Each edge is measured as the haversine distance between its endpoints, so the result follows
+/// the shortest path on the sphere rather than the straight line in longitude and latitude drawn
+/// by the polygon. Both the exterior ring and the holes are included, giving the total length of
+/// the fragment's boundary.
+///
+/// # Arguments
+///
+/// * `polygon` - A polygon with coordinates in decimal degrees (longitude, latitude)
+/// * `radius` - The radius of the sphere, e.g. `6371.0` for the Earth in kilometers
+///
+/// # Returns
+///
+/// * `f64` - The perimeter in the same unit as `radius`
+pub fn spherical_perimeter(polygon: &Polygon<f64>, radius: f64) -> f64 {
+    let angle: f64 = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.lines())
+        .map(|line| haversine_angle(&line.start, &line.end))
+        .sum();
+
+    angle * radius
+}
+
+/// Computes the unsigned area in steradians enclosed by a ring of longitude/latitude coordinates
+/// in decimal degrees.
+///
+/// Each edge contributes the area between itself and the south pole, `Δλ · (2 + sin φ1 + sin φ2) / 2`,
+/// which is exact for edges along meridians and parallels.
+fn spherical_ring_area(ring: &LineString<f64>) -> f64 {
+    let twice_area: f64 = ring.lines()
+        .map(|line| {
+            let delta_longitude: f64 = (line.end.x - line.start.x).to_radians();
+            delta_longitude * (2.0 + line.start.y.to_radians().sin() + line.end.y.to_radians().sin())
+        })
+        .sum();
+
+    twice_area.abs() / 2.0
+}
+
+/// Computes the unsigned area enclosed by a ring with the shoelace formula.
+fn ring_area(ring: &LineString<f64>) -> f64 {
+    let twice_area: f64 = ring.lines()
+        .map(|line| line.start.x * line.end.y - line.end.x * line.start.y)
+        .sum();
+
+    twice_area.abs() / 2.0
+}
+
+/// Returns true if a longitude/latitude pair in decimal degrees lies within the valid ranges,
+/// allowing `tolerance` degrees of slack for floating point error.
+fn is_coordinate_in_range(longitude: f64, latitude: f64, tolerance: f64) -> bool {
+    longitude.abs() <= 180.0 + tolerance && latitude.abs() <= 90.0 + tolerance
+}
+
 /// Projects a point from the unit sphere in 3D space onto a 2D plane using stereographic projection.
 ///
 /// Stereographic projection maps points from a sphere to a plane, preserving angles but not areas.
@@ -84,12 +392,66 @@ pub fn stereographic_projection(point: (f64, f64, f64)) -> Result<Coord<f64>, Ge
     Ok(coord! {x: x_2d, y: y_2d})
 }
 
+/// Projects a point from the unit sphere onto a 2D plane using stereographic projection, clamping
+/// points too close to the north pole instead of failing.
+///
+/// This behaves like [`stereographic_projection`], except that a point whose `z` is within
+/// `min_z_gap` of 1.0 is first moved along its meridian to `z = 1.0 - min_z_gap`, so it always
+/// projects to a finite coordinate. A point exactly on the pole, which has no meridian, is moved
+/// towards the positive x axis. Clamped points land very far from the origin and several points
+/// near the pole may be squeezed onto the same circle, so this introduces strong distortion in
+/// the vicinity of the pole.
+///
+/// # Arguments
+///
+/// * `point` - A 3D point (x, y, z) on or near the unit sphere
+/// * `min_z_gap` - Minimum distance along the z axis kept between the point and the north pole.
+///   Values below `f64::EPSILON` are raised to `f64::EPSILON`.
+///
+/// # Returns
+///
+/// * `Coord<f64>` - The projected 2D point (x_2d, y_2d)
+pub fn stereographic_projection_clamped(point: (f64, f64, f64), min_z_gap: f64) -> Coord<f64> {
+    let min_z_gap: f64 = min_z_gap.max(f64::EPSILON);
+    let (x, y, z) = clamp_below_north_pole(point, min_z_gap);
+
+    let x_2d: f64 = x / (1.0 - z);
+    let y_2d: f64 = y / (1.0 - z);
+
+    coord! {x: x_2d, y: y_2d}
+}
+
+/// Moves a point on the unit sphere along its meridian so that it is at least `min_z_gap` below the
+/// north pole. Points further away from the pole are returned unchanged.
+fn clamp_below_north_pole(point: (f64, f64, f64), min_z_gap: f64) -> (f64, f64, f64) {
+    let (x, y, z) = point;
+
+    if z < 1.0 - min_z_gap {
+        return point;
+    }
+
+    let z_clamped: f64 = 1.0 - min_z_gap;
+    let radius: f64 = (1.0 - z_clamped * z_clamped).sqrt();
+    let horizontal: f64 = x.hypot(y);
+
+    // a point exactly on the pole has no meridian, any direction will do
+    if horizontal < f64::EPSILON {
+        return (radius, 0.0, z_clamped);
+    }
+
+    (x / horizontal * radius, y / horizontal * radius, z_clamped)
+}
+
 /// Rotates a set of 3D points on a unit sphere so that their centroid aligns with the south pole.
 ///
 /// This function calculates the center point of the provided set of 3D points, then creates a rotation
 /// that maps this center to the south pole (0, 0, -1). All points in the set are then rotated using
 /// this same rotation matrix.
 ///
+/// When the centroid lies exactly on the north pole, for example for a polygon centered on (0, 90),
+/// there is no unique shortest rotation to the south pole, so the points are instead rotated by
+/// 180° around the x axis.
+///
 /// # Arguments
 ///
 /// * `points` - A vector of 3D points `(x, y, z)` on or near the unit sphere
@@ -100,22 +462,19 @@ pub fn stereographic_projection(point: (f64, f64, f64)) -> Result<Coord<f64>, Ge
 /// * `Err(GeoTilerError)` - An error if the rotation cannot be performed:
 ///   - `EmptyPointSetError` if the input vector is empty
 ///   - `RotationError` if the centroid of points is too close to zero (evenly distributed points)
-///   - `RotationError` if a rotation axis cannot be found (when points are at the north pole, 
-///     on the equator, or in other special configurations)
 pub fn rotate_points_to_south_pole(points: &Vec<(f64, f64, f64)>) -> Result<Vec<(f64, f64, f64)>, GeoTilerError> {
     if points.is_empty() {
         return Err(GeoTilerError::EmptyPointSetError("Cannot rotate an empty set of points".to_string()));
     }
 
-    let mut center = Vector3::new(0.0, 0.0, 0.0);
-    
-    for (x, y, z) in points.iter() {
-        center.x += x;
-        center.y += y;
-        center.z += z;
-    }
-    center /= points.len() as f64;
+    rotate_points_to_south_pole_about(points, mean_point(points))
+}
 
+/// Rotates a set of 3D points so that the given center aligns with the south pole.
+///
+/// This is the rotation used by [`rotate_points_to_south_pole`], with the center chosen by the
+/// caller instead of being the mean of the points.
+pub(crate) fn rotate_points_to_south_pole_about(points: &[(f64, f64, f64)], center: Vector3<f64>) -> Result<Vec<(f64, f64, f64)>, GeoTilerError> {
     // check if center is too small to normalize (should only happen if there's an even distribution of points in the set all over the sphere)
     if center.magnitude() < EPSILON {
         return Err(GeoTilerError::RotationError("Points centroid is effectively zero; cannot determine rotation direction".to_string()));
@@ -128,7 +487,11 @@ pub fn rotate_points_to_south_pole(points: &Vec<(f64, f64, f64)>) -> Result<Vec<
     // make rotation object which defines rotation between center of polygon and south pole
     let rotation: Rotation<f64, 3> = match Rotation3::rotation_between(&center, &south_pole) {
         Some(rotation) => rotation,
-        None => return Err(GeoTilerError::RotationError("Failed to compute rotation between points centroid and south pole".to_string())),
+        // the centroid is on the north pole, any half turn around an equatorial axis maps it to the south pole
+        None => {
+            log::debug!("Points centroid lies on the north pole, rotating by 180° around the x axis");
+            Rotation3::from_axis_angle(&Vector3::x_axis(), PI)
+        },
     };
 
     let mut rotated_points: Vec<(f64, f64, f64)> = Vec::with_capacity(points.len());
@@ -140,57 +503,345 @@ pub fn rotate_points_to_south_pole(points: &Vec<(f64, f64, f64)>) -> Result<Vec<
     Ok(rotated_points)
 }
 
+/// Angle in radians under which two vectors are interpolated linearly instead of spherically.
+const SLERP_LINEAR_THRESHOLD: f64 = 1e-6;
+
+/// Spherically interpolates between two points on the unit sphere.
+///
+/// The result moves at constant speed along the shorter great-circle arc from `a` to `b`, so
+/// `t = 0.5` gives the geodesic midpoint of the arc. The inputs are normalized first, and the
+/// result always lies on the unit sphere.
+///
+/// When the points are nearly identical, the arc is indistinguishable from a straight segment and
+/// the points are interpolated linearly before normalizing, which avoids dividing by a vanishing
+/// sine. When they are nearly antipodal, every great circle through them is equally short, so the
+/// arc through the axis least aligned with `a` is used.
+///
+/// # Arguments
+///
+/// * `a` - The start point `(x, y, z)`, returned for `t = 0`
+/// * `b` - The end point `(x, y, z)`, returned for `t = 1`
+/// * `t` - The interpolation parameter, usually in [0, 1]
+///
+/// # Returns
+///
+/// * `(f64, f64, f64)` - The interpolated point on the unit sphere. Zero or non-finite inputs
+///   give non-finite coordinates.
+pub fn slerp(a: (f64, f64, f64), b: (f64, f64, f64), t: f64) -> (f64, f64, f64) {
+    let a: Vector3<f64> = Vector3::new(a.0, a.1, a.2).normalize();
+    let b: Vector3<f64> = Vector3::new(b.0, b.1, b.2).normalize();
+
+    let angle: f64 = a.dot(&b).clamp(-1.0, 1.0).acos();
+
+    let interpolated: Vector3<f64> = if angle < SLERP_LINEAR_THRESHOLD {
+        a.lerp(&b, t).normalize()
+    } else if PI - angle < SLERP_LINEAR_THRESHOLD {
+        // any great circle joins antipodal points, rotate about an axis perpendicular to a
+        let least_aligned: Vector3<f64> = if a.x.abs() <= a.y.abs() && a.x.abs() <= a.z.abs() {
+            Vector3::x()
+        } else if a.y.abs() <= a.z.abs() {
+            Vector3::y()
+        } else {
+            Vector3::z()
+        };
+        let axis: Unit<Vector3<f64>> = Unit::new_normalize(a.cross(&least_aligned));
+        Rotation3::from_axis_angle(&axis, t * PI) * a
+    } else {
+        let sin_angle: f64 = angle.sin();
+        a * (((1.0 - t) * angle).sin() / sin_angle) + b * ((t * angle).sin() / sin_angle)
+    };
+
+    (interpolated.x, interpolated.y, interpolated.z)
+}
+
+/// Slack in degrees allowed beyond the valid longitude and latitude ranges to absorb floating point error.
+const COORDINATE_RANGE_TOLERANCE: f64 = 0.1;
+
+/// Mean radius of the Earth in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Tolerance in degrees under which an edge is considered vertical or horizontal.
+const AXIS_ALIGNED_EPSILON: f64 = 1e-6;
+
+/// Area in square degrees at or under which a polygon is considered to enclose no area.
+const ZERO_AREA_EPSILON: f64 = 1e-12;
+
+/// Unit in which the maximum edge length used during edge densification is expressed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DensifyDistance {
+    /// Maximum distance in degrees, measured in planar longitude/latitude space.
+    ///
+    /// The real-world spacing this produces shrinks towards the poles, since a degree
+    /// of longitude covers less ground at higher latitudes.
+    Degrees(f64),
+
+    /// Maximum distance in kilometers on the surface of the Earth.
+    ///
+    /// Edge lengths are approximated with an equirectangular projection, scaling longitude
+    /// differences by the cosine of the edge's mean latitude, so the spacing stays roughly
+    /// constant in real distance regardless of latitude.
+    Kilometers(f64),
+}
+
+/// Computes the area-weighted centroid of the spherical polygon enclosed by a ring of points on
+/// the unit sphere.
+///
+/// Unlike [`mean_point`], the result does not depend on how densely each part of the boundary is
+/// sampled. It is the direction of the integral of the position over the enclosed surface, which
+/// for a ring of great-circle arcs equals half the sum, over every edge, of the arc angle times the
+/// unit normal of the arc's plane. Of the two regions bounded by the ring, the one on the same side
+/// as the mean of the ring points is used, which is the polygon for any ring smaller than a
+/// hemisphere.
+///
+/// # Returns
+///
+/// * `Some(Vector3<f64>)` - The centroid, normalized onto the unit sphere
+/// * `None` - If the ring has fewer than 3 points or encloses no area
+pub(crate) fn spherical_polygon_centroid(ring: &[(f64, f64, f64)]) -> Option<Vector3<f64>> {
+    if ring.len() < 3 {
+        return None;
+    }
+
+    let mut moment: Vector3<f64> = Vector3::zeros();
+    for (i, a) in ring.iter().enumerate() {
+        let b: &(f64, f64, f64) = &ring[(i + 1) % ring.len()];
+        let a: Vector3<f64> = Vector3::new(a.0, a.1, a.2);
+        let b: Vector3<f64> = Vector3::new(b.0, b.1, b.2);
+
+        // skip repeated points such as the closing coordinate
+        let normal: Vector3<f64> = a.cross(&b);
+        let sine: f64 = normal.magnitude();
+        if sine < f64::EPSILON {
+            continue;
+        }
+
+        let angle: f64 = sine.atan2(a.dot(&b));
+        moment += normal / sine * angle;
+    }
+    moment /= 2.0;
+
+    // the winding of the ring decides which of the two regions it bounds is measured
+    if moment.dot(&mean_point(ring)) < 0.0 {
+        moment = -moment;
+    }
+
+    moment.try_normalize(f64::EPSILON)
+}
+
+/// Computes the arithmetic mean of a set of 3D points.
+///
+/// The result lies inside the sphere rather than on it and is the zero vector for an empty set.
+pub(crate) fn mean_point(points: &[(f64, f64, f64)]) -> Vector3<f64> {
+    let mut center = Vector3::new(0.0, 0.0, 0.0);
+
+    if points.is_empty() {
+        return center;
+    }
+    
+    for (x, y, z) in points.iter() {
+        center.x += x;
+        center.y += y;
+        center.z += z;
+    }
+    center /= points.len() as f64;
+
+    center
+}
+
 /// Adds intermediate points along polygon edges that exceed a specified maximum distance.
 ///
 /// This function subdivides long edges in a polygon by inserting evenly-spaced intermediate
 /// points, ensuring no edge segment is longer than the specified maximum distance. This is
 /// useful for improving the accuracy of geometric operations on polygons with large edges.
 ///
+/// Vertical and horizontal edges, such as those created by clipping against tile boundaries,
+/// are instead subdivided at fixed multiples of `max_distance` (e.g. every whole degree for a
+/// distance of 1.0). Adjacent tile fragments densified independently therefore get identical
+/// points along their shared boundary, keeping the tiled mesh watertight.
+///
+/// The polygon is left unchanged if `max_distance` is not a positive finite number, and edges
+/// with a non-finite endpoint are never subdivided.
+///
 /// # Arguments
 ///
 /// * `polygon` - A mutable reference to the polygon to be densified
-/// * `max_distance` - The maximum allowed distance between consecutive points along an edge
+/// * `max_distance` - The maximum allowed distance in degrees between consecutive points along an edge
 pub fn densify_edges(polygon: &mut Polygon, max_distance: f64) {
-    polygon.exterior_mut(|exterior| {
-        let mut new_coords: Vec<Coord> = Vec::new();
-        let coords: &Vec<Coord> = &exterior.0;
-        
-        if coords.len() < 2 {
-            return;
+    densify_edges_with_distance(polygon, DensifyDistance::Degrees(max_distance));
+}
+
+/// Adds intermediate points along polygon edges that exceed a specified maximum distance,
+/// expressed either in degrees or in kilometers.
+///
+/// This behaves like [`densify_edges`], but lets the caller choose the unit of the maximum
+/// distance. With [`DensifyDistance::Kilometers`], an east-west edge at 60° latitude receives
+/// about half as many intermediate points as the same edge at the equator, since it is about
+/// half as long on the ground.
+///
+/// # Arguments
+///
+/// * `polygon` - A mutable reference to the polygon to be densified
+/// * `max_distance` - The maximum allowed distance between consecutive points along an edge
+pub fn densify_edges_with_distance(polygon: &mut Polygon, max_distance: DensifyDistance) {
+    densify_rings(polygon, max_distance, |_, _| true);
+}
+
+/// Adds intermediate points only along polygon edges that cross or come close to a grid line.
+///
+/// Densifying a whole polygon before clipping is wasteful, since only the edges near tile
+/// boundaries benefit from extra points when clipped. This function considers the grid lines
+/// of [`generate_grid`](crate::generate_grid), at longitudes `-180 + k * step` and latitudes
+/// `-90 + k * step`, and densifies an edge only if its bounding box crosses one of them or lies
+/// within `max_distance` of one. All other edges are left untouched.
+///
+/// # Arguments
+///
+/// * `polygon` - A mutable reference to the polygon to be densified
+/// * `step` - The angular step size in degrees of the grid
+/// * `max_distance` - The maximum allowed distance in degrees between consecutive points along
+///   densified edges, also used as the proximity threshold to grid lines
+pub fn densify_edges_near_grid(polygon: &mut Polygon, step: f64, max_distance: f64) {
+    if step <= 0.0 || !step.is_finite() {
+        return;
+    }
+
+    densify_rings(polygon, DensifyDistance::Degrees(max_distance), |c1, c2| {
+        is_near_grid_line(c1.x.min(c2.x), c1.x.max(c2.x), -180.0, step, max_distance)
+            || is_near_grid_line(c1.y.min(c2.y), c1.y.max(c2.y), -90.0, step, max_distance)
+    });
+}
+
+/// Returns true if the interval [min, max] contains a grid line `origin + k * step`
+/// or lies within `max_distance` of one.
+fn is_near_grid_line(min: f64, max: f64, origin: f64, step: f64, max_distance: f64) -> bool {
+    let previous_line: f64 = origin + ((min - origin) / step).floor() * step;
+    let next_line: f64 = previous_line + step;
+
+    next_line <= max || min - previous_line <= max_distance || next_line - max <= max_distance
+}
+
+/// Densifies the exterior and interior rings of a polygon, subdividing only the edges for which
+/// `should_densify` returns true.
+fn densify_rings(polygon: &mut Polygon, max_distance: DensifyDistance, should_densify: impl Fn(&Coord, &Coord) -> bool) {
+    let (DensifyDistance::Degrees(distance) | DensifyDistance::Kilometers(distance)) = max_distance;
+    if !distance.is_finite() || distance <= 0.0 {
+        return;
+    }
+
+    polygon.exterior_mut(|exterior| densify_ring(exterior, max_distance, &should_densify));
+    polygon.interiors_mut(|interiors| {
+        for interior in interiors {
+            densify_ring(interior, max_distance, &should_densify);
         }
-        
-        new_coords.push(coords[0]); // add first point
-        
-        for i in 0..(coords.len() - 1) {
-            let c1: Coord = coords[i];
-            let c2: Coord = coords[i + 1];
-            let distance: f64 = distance_between(&c1, &c2);
-            
-            if distance > max_distance {
-                let num_segments: usize = (distance / max_distance).ceil() as usize;
-                
-                // add intermediate points
+    });
+}
+
+/// Densifies a single ring, subdividing only the edges for which `should_densify` returns true.
+/// The closing coordinate is left out, since the polygon closes the ring again afterwards.
+fn densify_ring(ring: &mut LineString, max_distance: DensifyDistance, should_densify: &impl Fn(&Coord, &Coord) -> bool) {
+    let mut new_coords: Vec<Coord> = Vec::new();
+    let coords: &Vec<Coord> = &ring.0;
+    
+    if coords.len() < 2 {
+        return;
+    }
+    
+    new_coords.push(coords[0]); // add first point
+    
+    for i in 0..(coords.len() - 1) {
+        let c1: Coord = coords[i];
+        let c2: Coord = coords[i + 1];
+
+        // add intermediate points, leaving edges with NaN or infinite endpoints untouched
+        let finite: bool = c1.x.is_finite() && c1.y.is_finite() && c2.x.is_finite() && c2.y.is_finite();
+        if finite && should_densify(&c1, &c2) {
+            if (c2.x - c1.x).abs() < AXIS_ALIGNED_EPSILON || (c2.y - c1.y).abs() < AXIS_ALIGNED_EPSILON {
+                push_aligned_points(&mut new_coords, &c1, &c2, max_distance);
+            } else {
+                let num_segments: usize = segment_count(&c1, &c2, max_distance);
                 for j in 1..num_segments {
                     let t: f64 = j as f64 / num_segments as f64;
                     let interpolated: Coord = interpolate_point(&c1, &c2, t);
                     new_coords.push(interpolated);
                 }
             }
-            
-            if i < coords.len() - 2 {
-                new_coords.push(c2);
-            }
         }
         
-        let last_coord: Coord = coords[coords.len() - 1];
-        if coords.len() > 2 && last_coord != coords[0] {
-            new_coords.push(last_coord);
+        if i < coords.len() - 2 {
+            new_coords.push(c2);
         }
-        
-        exterior.0 = new_coords;
-    });
+    }
+    
+    let last_coord: Coord = coords[coords.len() - 1];
+    if coords.len() > 2 && last_coord != coords[0] {
+        new_coords.push(last_coord);
+    }
+    
+    ring.0 = new_coords;
 }
 
+/// Adds intermediate points to a vertical or horizontal edge at fixed multiples of the
+/// angular step along its varying coordinate.
+///
+/// Because the positions only depend on the line the edge lies on, two edges on the same
+/// line (such as the shared boundary of two adjacent tile fragments) get identical points
+/// wherever they overlap, regardless of their endpoints or direction. This holds for edges
+/// shorter than the step too: a short edge still receives the multiple it crosses.
+fn push_aligned_points(new_coords: &mut Vec<Coord>, c1: &Coord<f64>, c2: &Coord<f64>, max_distance: DensifyDistance) {
+    let vertical: bool = (c2.x - c1.x).abs() < AXIS_ALIGNED_EPSILON;
+    let (start, end) = if vertical { (c1.y, c2.y) } else { (c1.x, c2.x) };
+
+    let step: f64 = match max_distance {
+        DensifyDistance::Degrees(max_distance) => max_distance,
+        DensifyDistance::Kilometers(max_distance) => {
+            let step: f64 = (max_distance / EARTH_RADIUS_KM).to_degrees();
+            if vertical { step } else { step / c1.y.to_radians().cos() }
+        }
+    };
+
+    if !step.is_finite() || step <= 0.0 {
+        return;
+    }
+
+    // multiples of the step strictly between the endpoints, in the direction of travel
+    let first: i64 = (start.min(end) / step).floor() as i64 + 1;
+    let last: i64 = (start.max(end) / step).ceil() as i64 - 1;
+    let mut multiples: Vec<i64> = (first..=last)
+        .filter(|k| {
+            let position: f64 = *k as f64 * step;
+            (position - start).abs() > AXIS_ALIGNED_EPSILON && (position - end).abs() > AXIS_ALIGNED_EPSILON
+        })
+        .collect();
+    if end < start {
+        multiples.reverse();
+    }
+
+    for k in multiples {
+        let t: f64 = (k as f64 * step - start) / (end - start);
+        let mut interpolated: Coord = interpolate_point(c1, c2, t);
+        if vertical {
+            interpolated.y = k as f64 * step;
+        } else {
+            interpolated.x = k as f64 * step;
+        }
+        new_coords.push(interpolated);
+    }
+}
+
+/// Returns the number of segments an edge must be split into so that no segment
+/// exceeds `max_distance`.
+fn segment_count(c1: &Coord<f64>, c2: &Coord<f64>, max_distance: DensifyDistance) -> usize {
+    let (distance, max_distance) = match max_distance {
+        DensifyDistance::Degrees(max_distance) => (distance_between(c1, c2), max_distance),
+        DensifyDistance::Kilometers(max_distance) => (kilometer_distance_between(c1, c2), max_distance),
+    };
+
+    if distance > max_distance {
+        (distance / max_distance).ceil() as usize
+    } else {
+        1
+    }
+}
 
 fn distance_between(c1: &Coord<f64>, c2: &Coord<f64>) -> f64 {
     let dx: f64 = c2.x - c1.x;
@@ -198,6 +849,24 @@ fn distance_between(c1: &Coord<f64>, c2: &Coord<f64>) -> f64 {
     (dx * dx + dy * dy).sqrt()
 }
 
+fn kilometer_distance_between(c1: &Coord<f64>, c2: &Coord<f64>) -> f64 {
+    let mean_latitude_rad: f64 = ((c1.y + c2.y) / 2.0).to_radians();
+    let dx: f64 = (c2.x - c1.x).to_radians() * mean_latitude_rad.cos();
+    let dy: f64 = (c2.y - c1.y).to_radians();
+    (dx * dx + dy * dy).sqrt() * EARTH_RADIUS_KM
+}
+
+/// Computes the great-circle angle in radians between two longitude/latitude coordinates in
+/// decimal degrees with the haversine formula, which stays accurate for nearby points.
+pub(crate) fn haversine_angle(c1: &Coord<f64>, c2: &Coord<f64>) -> f64 {
+    let half_dlat: f64 = (c2.y - c1.y).to_radians() / 2.0;
+    let half_dlon: f64 = (c2.x - c1.x).to_radians() / 2.0;
+    let h: f64 = half_dlat.sin().powi(2)
+        + c1.y.to_radians().cos() * c2.y.to_radians().cos() * half_dlon.sin().powi(2);
+
+    2.0 * h.sqrt().min(1.0).asin()
+}
+
 fn interpolate_point(c1: &Coord<f64>, c2: &Coord<f64>, t: f64) -> Coord<f64> {
     Coord {
         x: c1.x + t * (c2.x - c1.x),
@@ -241,4 +910,320 @@ fn sanitize_coordinates(longitude: f64, latitude: f64, epsilon: f64) -> (f64, f6
     };
     
     (sanitized_longitude, sanitized_latitude)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::polygon;
+
+    #[test]
+    fn test_kilometer_densification_accounts_for_latitude() {
+        let mut equator: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 0.1), (x: 0.0, y: 0.1)];
+        let mut high_latitude: Polygon = polygon![(x: 0.0, y: 60.0), (x: 10.0, y: 60.0), (x: 10.0, y: 60.1), (x: 0.0, y: 60.1)];
+
+        densify_edges_with_distance(&mut equator, DensifyDistance::Kilometers(100.0));
+        densify_edges_with_distance(&mut high_latitude, DensifyDistance::Kilometers(100.0));
+
+        // a degree of longitude at 60° is half as long as at the equator
+        let equator_points: usize = equator.exterior().0.len();
+        let high_latitude_points: usize = high_latitude.exterior().0.len();
+        assert!(equator_points > high_latitude_points);
+        assert!(equator_points >= 2 * high_latitude_points - 6);
+
+        let mut degrees: Polygon = polygon![(x: 0.0, y: 60.0), (x: 10.0, y: 60.0), (x: 10.0, y: 60.1), (x: 0.0, y: 60.1)];
+        densify_edges_with_distance(&mut degrees, DensifyDistance::Degrees(1.0));
+        let mut degrees_equator: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 0.1), (x: 0.0, y: 0.1)];
+        densify_edges_with_distance(&mut degrees_equator, DensifyDistance::Degrees(1.0));
+        assert_eq!(degrees.exterior().0.len(), degrees_equator.exterior().0.len());
+    }
+
+
+    #[test]
+    fn test_short_edge_gets_aligned_point() {
+        // the bottom edge is shorter than the step but crosses the multiple at x = 2
+        let mut short: Polygon = polygon![(x: 1.5, y: 0.0), (x: 2.5, y: 0.0), (x: 2.5, y: 1.0), (x: 1.5, y: 1.0)];
+        densify_edges_with_distance(&mut short, DensifyDistance::Degrees(2.0));
+        assert!(short.exterior().0.contains(&coord! {x: 2.0, y: 0.0}));
+
+        // a neighbouring fragment sharing the bottom line, densified independently
+        let mut long: Polygon = polygon![(x: 0.5, y: 0.0), (x: 5.5, y: 0.0), (x: 5.5, y: -1.0), (x: 0.5, y: -1.0)];
+        densify_edges_with_distance(&mut long, DensifyDistance::Degrees(2.0));
+        let on_shared_line = |polygon: &Polygon| -> Vec<Coord> {
+            polygon.exterior().0.iter().copied().filter(|c| c.y == 0.0 && c.x > 1.5 && c.x < 2.5).collect()
+        };
+        assert_eq!(on_shared_line(&short), vec![coord! {x: 2.0, y: 0.0}]);
+        assert_eq!(on_shared_line(&short), on_shared_line(&long));
+    }
+
+
+    #[test]
+    fn test_validate_polygon_coordinates_reports_every_bad_coordinate() {
+        let valid: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+        assert!(validate_polygon_coordinates(&valid).is_ok());
+
+        let exterior: LineString = LineString::from(vec![(0.0, 0.0), (200.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]);
+        let hole: LineString = LineString::from(vec![(2.0, 2.0), (4.0, 2.0), (4.0, f64::NAN), (2.0, 2.0)]);
+        let invalid: Polygon = Polygon::new(exterior, vec![hole]);
+
+        match validate_polygon_coordinates(&invalid) {
+            Err(GeoTilerError::InvalidPolygonError(message)) => {
+                assert!(message.contains("2 invalid coordinate(s)"));
+                assert!(message.contains("ring 0 index 1: (200, 0) is out of range"));
+                assert!(message.contains("ring 1 index 2: (4, NaN) is not finite"));
+            }
+            other => panic!("expected an InvalidPolygonError, got {:?}", other),
+        }
+    }
+
+
+    #[test]
+    fn test_densify_edges_near_grid_only_touches_edges_near_grid_lines() {
+        // with a 20° grid starting at -180°, the lines near this polygon are x = 0, x = 20 and y = 10
+        let mut polygon: Polygon = polygon![(x: 2.0, y: 2.0), (x: 18.0, y: 2.0), (x: 18.0, y: 8.0), (x: 2.0, y: 8.0)];
+        let original: Polygon = polygon.clone();
+        densify_edges_near_grid(&mut polygon, 20.0, 1.0);
+        assert_eq!(polygon, original);
+
+        // the vertical edges now cross y = 10, while the horizontal edges stay away from every grid line
+        let mut crossing: Polygon = polygon![(x: 2.0, y: 2.0), (x: 18.0, y: 2.0), (x: 18.0, y: 14.0), (x: 2.0, y: 14.0)];
+        densify_edges_near_grid(&mut crossing, 20.0, 1.0);
+        let coords: &Vec<Coord> = &crossing.exterior().0;
+        assert!(coords.iter().any(|c| c.x == 18.0 && c.y > 2.0 && c.y < 14.0));
+        assert!(!coords.iter().any(|c| c.y == 2.0 && c.x > 2.0 && c.x < 18.0));
+        assert!(!coords.iter().any(|c| c.y == 14.0 && c.x > 2.0 && c.x < 18.0));
+    }
+
+
+    #[test]
+    fn test_clamped_projection_of_north_pole_is_large_but_finite() {
+        assert!(stereographic_projection((0.0, 0.0, 1.0)).is_err());
+
+        let projected: Coord = stereographic_projection_clamped((0.0, 0.0, 1.0), 1e-6);
+        assert!(projected.x.is_finite() && projected.y.is_finite());
+        assert!(projected.x.hypot(projected.y) > 1e3);
+
+        // points away from the pole project exactly like the unclamped projection
+        let point: (f64, f64, f64) = (0.6, 0.0, -0.8);
+        assert_eq!(stereographic_projection_clamped(point, 1e-6), stereographic_projection(point).unwrap());
+    }
+
+
+    #[test]
+    fn test_ll_to_cartesian_tolerance_boundary() {
+        assert!(ll_to_cartesian_with_tolerance(180.15, 0.0, 0.2).is_ok());
+        assert!(matches!(ll_to_cartesian(180.15, 0.0), Err(GeoTilerError::CoordinateRangeError { .. })));
+        assert!(ll_to_cartesian_with_tolerance(180.25, 0.0, 0.2).is_err());
+        assert!(ll_to_cartesian_with_tolerance(0.0, -90.15, 0.2).is_ok());
+        assert!(ll_to_cartesian(0.0, -90.15).is_err());
+
+        // a point within the slack lands right next to the nearest in-range point
+        let (x, y, z) = ll_to_cartesian_with_tolerance(180.15, 0.0, 0.2).unwrap();
+        let (ex, ey, ez) = ll_to_cartesian(180.0, 0.0).unwrap();
+        assert!((x - ex).abs() < 1e-2 && (y - ey).abs() < 1e-2 && (z - ez).abs() < 1e-2);
+    }
+
+
+    #[test]
+    fn test_area_weighted_centroid_ignores_clustered_vertices() {
+        // a 20° square whose east edge is sampled far more densely than the others
+        let mut lonlat: Vec<(f64, f64)> = vec![(0.0, 0.0), (20.0, 0.0)];
+        lonlat.extend((1..200).map(|i| (20.0, i as f64 * 0.1)));
+        lonlat.extend([(20.0, 20.0), (0.0, 20.0)]);
+        let ring: Vec<(f64, f64, f64)> = lonlat.iter().map(|&(lon, lat)| ll_to_cartesian(lon, lat).unwrap()).collect();
+
+        let to_lonlat = |v: Vector3<f64>| cartesian_to_ll((v.x, v.y, v.z)).unwrap();
+        let (centroid_lon, centroid_lat) = to_lonlat(spherical_polygon_centroid(&ring).unwrap());
+        let (mean_lon, _) = to_lonlat(mean_point(&ring));
+
+        assert!((centroid_lon - 10.0).abs() < 0.5);
+        assert!((centroid_lat - 10.0).abs() < 0.5);
+        assert!(mean_lon > 17.0);
+
+        // degenerate rings have no area-weighted centroid
+        assert!(spherical_polygon_centroid(&ring[..2]).is_none());
+        let collinear: Vec<(f64, f64, f64)> = [(0.0, 0.0), (10.0, 0.0), (20.0, 0.0)]
+            .iter()
+            .map(|&(lon, lat)| ll_to_cartesian(lon, lat).unwrap())
+            .collect();
+        assert!(spherical_polygon_centroid(&collinear).is_none());
+    }
+
+
+    #[test]
+    fn test_planar_polygon_area() {
+        let unit_square: Polygon = polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 1.0)];
+        assert!((planar_polygon_area(&unit_square) - 1.0).abs() < 1e-12);
+
+        // winding does not matter
+        let clockwise: Polygon = polygon![(x: 0.0, y: 0.0), (x: 0.0, y: 1.0), (x: 1.0, y: 1.0), (x: 1.0, y: 0.0)];
+        assert!((planar_polygon_area(&clockwise) - 1.0).abs() < 1e-12);
+
+        let with_hole: Polygon = polygon!(
+            exterior: [(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0), (x: 1.0, y: 2.0)]],
+        );
+        assert!((planar_polygon_area(&with_hole) - 15.0).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn test_densify_edges_ignores_invalid_distances_and_non_finite_edges() {
+        let square: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+
+        for max_distance in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let mut densified: Polygon = square.clone();
+            densify_edges(&mut densified, max_distance);
+            assert_eq!(densified, square);
+        }
+
+        let mut with_nan: Polygon = polygon![(x: 0.0, y: 0.0), (x: f64::NAN, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+        densify_edges(&mut with_nan, 1.0);
+        // only the two edges between finite corners are subdivided
+        assert_eq!(with_nan.exterior().0.len(), 5 + 9 + 9);
+        assert_eq!(with_nan.exterior().0.iter().filter(|coord| coord.x.is_nan()).count(), 1);
+    }
+
+
+    #[test]
+    fn test_slerp_midpoint_of_equatorial_points() {
+        let a: (f64, f64, f64) = ll_to_cartesian(10.0, 0.0).unwrap();
+        let b: (f64, f64, f64) = ll_to_cartesian(70.0, 0.0).unwrap();
+
+        let midpoint: (f64, f64, f64) = slerp(a, b, 0.5);
+        let norm: f64 = (midpoint.0 * midpoint.0 + midpoint.1 * midpoint.1 + midpoint.2 * midpoint.2).sqrt();
+        assert!((norm - 1.0).abs() < 1e-12);
+
+        let (longitude, latitude): (f64, f64) = cartesian_to_ll(midpoint).unwrap();
+        assert!((longitude - 40.0).abs() < 1e-9);
+        assert!(latitude.abs() < 1e-9);
+
+        // constant speed along the arc
+        let (quarter, _): (f64, f64) = cartesian_to_ll(slerp(a, b, 0.25)).unwrap();
+        assert!((quarter - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_slerp_nearly_identical_and_antipodal_points() {
+        let a: (f64, f64, f64) = ll_to_cartesian(20.0, 0.0).unwrap();
+        let nearly_a: (f64, f64, f64) = ll_to_cartesian(20.0 + 1e-9, 0.0).unwrap();
+        let close: (f64, f64, f64) = slerp(a, nearly_a, 0.5);
+        assert!(close.0.is_finite() && close.1.is_finite() && close.2.is_finite());
+        assert!((close.0 - a.0).abs() < 1e-9 && (close.1 - a.1).abs() < 1e-9 && (close.2 - a.2).abs() < 1e-9);
+
+        let antipode: (f64, f64, f64) = (-a.0, -a.1, -a.2);
+        let halfway: (f64, f64, f64) = slerp(a, antipode, 0.5);
+        let norm: f64 = (halfway.0 * halfway.0 + halfway.1 * halfway.1 + halfway.2 * halfway.2).sqrt();
+        assert!((norm - 1.0).abs() < 1e-12);
+        // a quarter turn away from both ends
+        assert!((halfway.0 * a.0 + halfway.1 * a.1 + halfway.2 * a.2).abs() < 1e-9);
+        let end: (f64, f64, f64) = slerp(a, antipode, 1.0);
+        assert!((end.0 - antipode.0).abs() < 1e-9 && (end.1 - antipode.1).abs() < 1e-9 && (end.2 - antipode.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fragment_angular_extent() {
+        let mut grid: Vec<crate::Tile> = crate::generate_grid(20).unwrap();
+        let polygon: Polygon = polygon![(x: 12.0, y: 3.0), (x: 31.0, y: 5.0), (x: 28.0, y: 14.5), (x: 15.0, y: 11.0)];
+        crate::clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        // the fragment in the tile from (0, -10) to (20, 10) is cut at its east and north edges
+        let fragment: &Polygon = grid.iter()
+            .find(|tile| tile.vertices.exterior().0[0] == coord! {x: 0.0, y: -10.0})
+            .map(|tile| &tile.polygons[0])
+            .unwrap();
+        let (longitude_span, latitude_span): (f64, f64) = fragment_angular_extent(fragment);
+        assert!((longitude_span - 8.0).abs() < 1e-9);
+        assert!((latitude_span - 7.0).abs() < 1e-9);
+
+        let (longitude_span, latitude_span): (f64, f64) = fragment_angular_extent(&polygon);
+        assert_eq!((longitude_span, latitude_span), (19.0, 11.5));
+
+        assert_eq!(fragment_angular_extent(&Polygon::new(LineString::new(vec![]), vec![])), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_polygon_exceeds_hemisphere() {
+        let giant: Polygon = polygon![(x: -170.0, y: -80.0), (x: 170.0, y: -80.0), (x: 170.0, y: 80.0), (x: -170.0, y: 80.0)];
+        assert!(polygon_exceeds_hemisphere(&giant));
+        assert!(matches!(crate::generate_polygon_feature_mesh(&giant), Err(GeoTilerError::MeshGenerationError(_))));
+
+        // the northern hemisphere is exactly 2π steradians, one more degree of latitude exceeds it
+        let northern: Polygon = polygon![(x: -180.0, y: 0.0), (x: 180.0, y: 0.0), (x: 180.0, y: 90.0), (x: -180.0, y: 90.0)];
+        assert!(!polygon_exceeds_hemisphere(&northern));
+        let beyond: Polygon = polygon![(x: -180.0, y: -1.0), (x: 180.0, y: -1.0), (x: 180.0, y: 90.0), (x: -180.0, y: 90.0)];
+        assert!(polygon_exceeds_hemisphere(&beyond));
+
+        // a hole can bring a polygon back under a hemisphere
+        let hole: LineString = LineString::from(vec![(-170.0, 0.0), (170.0, 0.0), (170.0, 80.0), (-170.0, 80.0), (-170.0, 0.0)]);
+        let with_hole: Polygon = Polygon::new(giant.exterior().clone(), vec![hole]);
+        assert!(!polygon_exceeds_hemisphere(&with_hole));
+
+        let small: Polygon = polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 20.0, y: 20.0), (x: 0.0, y: 20.0)];
+        assert!(!polygon_exceeds_hemisphere(&small));
+    }
+
+    #[test]
+    fn test_spherical_perimeter() {
+        let radius: f64 = 6371.0;
+
+        // a small square at the equator is nearly planar
+        let small: Polygon<f64> = polygon![(x: 10.0, y: 0.0), (x: 10.01, y: 0.0), (x: 10.01, y: 0.01), (x: 10.0, y: 0.01)];
+        let planar: f64 = 0.04_f64.to_radians() * radius;
+        assert!((spherical_perimeter(&small, radius) - planar).abs() < planar * 1e-6);
+
+        // the octant triangle has three quarter great circles as edges, while its planar perimeter
+        // includes a 90√2° diagonal
+        let octant: Polygon<f64> = polygon![(x: 0.0, y: 0.0), (x: 90.0, y: 0.0), (x: 0.0, y: 90.0)];
+        assert!((spherical_perimeter(&octant, 1.0) - 1.5 * PI).abs() < 1e-9);
+        let octant_planar: f64 = (180.0 + 90.0 * 2.0_f64.sqrt()).to_radians();
+        assert!(octant_planar - spherical_perimeter(&octant, 1.0) > 0.6);
+
+        let holed: Polygon<f64> = Polygon::new(
+            small.exterior().clone(),
+            vec![LineString::from(vec![(10.002, 0.002), (10.004, 0.002), (10.004, 0.004), (10.002, 0.004), (10.002, 0.002)])],
+        );
+        let hole_perimeter: f64 = 0.008_f64.to_radians() * radius;
+        assert!((spherical_perimeter(&holed, radius) - planar - hole_perimeter).abs() < planar * 1e-6);
+    }
+
+    #[test]
+    fn test_ensure_exterior_is_largest_swaps_misplaced_outer_ring() {
+        let outer: LineString<f64> = LineString::from(vec![(0.0, 0.0), (30.0, 0.0), (30.0, 30.0), (0.0, 30.0), (0.0, 0.0)]);
+        let hole_a: LineString<f64> = LineString::from(vec![(5.0, 5.0), (5.0, 10.0), (10.0, 10.0), (10.0, 5.0), (5.0, 5.0)]);
+        let hole_b: LineString<f64> = LineString::from(vec![(20.0, 20.0), (20.0, 22.0), (22.0, 22.0), (22.0, 20.0), (20.0, 20.0)]);
+
+        let misconstructed: Polygon<f64> = Polygon::new(hole_a.clone(), vec![hole_b.clone(), outer.clone()]);
+        let corrected: Polygon<f64> = ensure_exterior_is_largest(&misconstructed);
+
+        assert_eq!(corrected.exterior(), &outer);
+        assert_eq!(corrected.interiors(), &[hole_b.clone(), hole_a.clone()]);
+        assert!((planar_polygon_area(&corrected) - (900.0 - 25.0 - 4.0)).abs() < 1e-9);
+
+        let correct: Polygon<f64> = Polygon::new(outer, vec![hole_a, hole_b]);
+        assert_eq!(ensure_exterior_is_largest(&correct), correct);
+    }
+
+    #[test]
+    fn test_rotating_points_centered_on_north_pole() {
+        let z: f64 = (1.0_f64 - 0.01).sqrt();
+        let points: Vec<(f64, f64, f64)> = vec![(0.1, 0.0, z), (-0.1, 0.0, z), (0.0, 0.1, z), (0.0, -0.1, z)];
+
+        let rotated: Vec<(f64, f64, f64)> = rotate_points_to_south_pole(&points).unwrap();
+
+        assert_eq!(rotated.len(), points.len());
+        for (&(x, y, z), original) in rotated.iter().zip(&points) {
+            assert!(x.is_finite() && y.is_finite() && z.is_finite());
+            let length: f64 = (x * x + y * y + z * z).sqrt();
+            let original_length: f64 = (original.0 * original.0 + original.1 * original.1 + original.2 * original.2).sqrt();
+            assert!((length - original_length).abs() < 1e-12);
+        }
+        let center: Vector3<f64> = mean_point(&rotated);
+        assert!(center.x.abs() < 1e-12 && center.y.abs() < 1e-12);
+        assert!((center.z + z).abs() < 1e-12);
+
+        // the north pole itself is sent to the south pole
+        let pole: Vec<(f64, f64, f64)> = rotate_points_to_south_pole_about(&[(0.0, 0.0, 1.0)], Vector3::new(0.0, 0.0, 1.0)).unwrap();
+        assert!(pole[0].0.abs() < 1e-12 && pole[0].1.abs() < 1e-12 && (pole[0].2 + 1.0).abs() < 1e-12);
+    }
+}
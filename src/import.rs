@@ -0,0 +1,211 @@
+use geo::{coord, Coord, LineString, Polygon};
+use geojson::{Feature, PolygonType, Value};
+use crate::GeoTilerError;
+
+/// Extracts every polygon of a list of GeoJSON features, ready to be clipped to a grid.
+///
+/// `Polygon` and `MultiPolygon` geometries are converted directly, and `GeometryCollection`
+/// geometries are searched recursively for them. Features without a geometry and polygons
+/// without any ring cannot be tiled, so they are skipped and reported with a `log::warn!`
+/// instead of failing the whole dataset.
+///
+/// # Arguments
+///
+/// * `features` - The features to read, such as the `features` of a `FeatureCollection`
+///
+/// # Returns
+///
+/// * `Ok(Vec<Polygon<f64>>)` - The polygons of every feature, in feature order
+/// * `Err(GeoTilerError::GeoJsonError)` - If a feature holds a geometry other than a
+///   `Polygon`, `MultiPolygon` or `GeometryCollection`
+pub fn feature_polygons(features: &[Feature]) -> Result<Vec<Polygon<f64>>, GeoTilerError> {
+    let mut polygons: Vec<Polygon<f64>> = Vec::new();
+    let mut skipped_features: usize = 0;
+
+    for feature in features {
+        match feature.geometry.as_ref() {
+            Some(geometry) => collect_polygons(&geometry.value, &mut polygons)?,
+            None => {
+                skipped_features += 1;
+                log::warn!("Skipping feature without a geometry: {}", feature);
+            }
+        }
+    }
+
+    if skipped_features > 0 {
+        log::warn!("Skipped {} feature(s) without a geometry", skipped_features);
+    }
+
+    Ok(polygons)
+}
+
+/// Collects the polygons contained in a GeoJSON geometry, recursing into geometry collections.
+fn collect_polygons(value: &Value, polygons: &mut Vec<Polygon<f64>>) -> Result<(), GeoTilerError> {
+    match value {
+        Value::Polygon(polygon) => push_polygon(polygon, polygons),
+        Value::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon {
+                push_polygon(polygon, polygons);
+            }
+        }
+        Value::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                collect_polygons(&geometry.value, polygons)?;
+            }
+        }
+        _ => return Err(GeoTilerError::GeoJsonError {
+            message: "Expected a Polygon, MultiPolygon or GeometryCollection as a geometry".to_string(),
+            source: None,
+        }),
+    }
+
+    Ok(())
+}
+
+/// Converts a GeoJSON polygon and adds it to `polygons`, skipping it with a warning if it has
+/// no rings.
+fn push_polygon(rings: &PolygonType, polygons: &mut Vec<Polygon<f64>>) {
+    match polygon_from_rings(rings) {
+        Ok(polygon) => polygons.push(polygon),
+        Err(e) => log::warn!("Skipping polygon: {}", e),
+    }
+}
+
+fn polygon_from_rings(rings: &PolygonType) -> Result<Polygon<f64>, GeoTilerError> {
+    let mut rings: Vec<LineString<f64>> = rings
+        .iter()
+        .map(|ring| ring.iter().map(|pos| coord! {x: pos[0], y: pos[1]}).collect::<Vec<Coord<f64>>>().into())
+        .collect();
+    if rings.is_empty() {
+        return Err(GeoTilerError::InvalidPolygonError("Polygon has no coordinates".to_string()));
+    }
+    let outer_ring: LineString<f64> = rings.remove(0);
+
+    Ok(Polygon::new(outer_ring, rings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, Once};
+    use geojson::Geometry;
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+    use crate::{clip_polygon_to_tiles, generate_grid, Tile};
+
+    /// Logger recording every warning so tests can check what was reported.
+    struct CapturingLogger {
+        warnings: Mutex<Vec<String>>,
+    }
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= Level::Warn
+        }
+
+        fn log(&self, record: &Record) {
+            if self.enabled(record.metadata()) {
+                self.warnings.lock().unwrap().push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger { warnings: Mutex::new(Vec::new()) };
+    static INIT_LOGGER: Once = Once::new();
+
+    fn captured_warnings() -> &'static Mutex<Vec<String>> {
+        INIT_LOGGER.call_once(|| {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(LevelFilter::Warn);
+        });
+
+        &LOGGER.warnings
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> PolygonType {
+        vec![vec![vec![x, y], vec![x + size, y], vec![x + size, y + size], vec![x, y + size], vec![x, y]]]
+    }
+
+    fn feature(value: Option<Value>) -> Feature {
+        Feature {
+            geometry: value.map(Geometry::new),
+            ..Feature::default()
+        }
+    }
+
+    #[test]
+    fn test_feature_without_geometry_is_skipped_with_a_warning() {
+        let warnings: &Mutex<Vec<String>> = captured_warnings();
+
+        let features: Vec<Feature> = vec![feature(None), feature(Some(Value::Polygon(square(0.0, 0.0, 5.0))))];
+        let polygons: Vec<Polygon<f64>> = feature_polygons(&features).unwrap();
+
+        assert_eq!(polygons.len(), 1);
+        let warnings: Vec<String> = warnings.lock().unwrap().clone();
+        assert!(warnings.iter().any(|warning| warning.starts_with("Skipping feature without a geometry")));
+        assert!(warnings.iter().any(|warning| warning == "Skipped 1 feature(s) without a geometry"));
+    }
+
+    #[test]
+    fn test_geometry_collections_are_searched_recursively() {
+        let collection: Value = Value::GeometryCollection(vec![
+            Geometry::new(Value::Polygon(square(0.0, 0.0, 5.0))),
+            Geometry::new(Value::GeometryCollection(vec![
+                Geometry::new(Value::MultiPolygon(vec![square(40.0, 10.0, 5.0), square(-60.0, -30.0, 5.0)])),
+            ])),
+        ]);
+
+        let polygons: Vec<Polygon<f64>> = feature_polygons(&[feature(Some(collection))]).unwrap();
+        assert_eq!(polygons.len(), 3);
+
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        for polygon in &polygons {
+            clip_polygon_to_tiles(&mut grid, polygon).unwrap();
+        }
+        let fragment_count: usize = grid.iter().map(|tile| tile.polygons.len()).sum();
+        assert_eq!(fragment_count, 3);
+    }
+
+    #[test]
+    fn test_empty_polygon_is_skipped() {
+        assert!(polygon_from_rings(&Vec::new()).is_err());
+
+        let multi_polygon: Value = Value::MultiPolygon(vec![Vec::new(), square(0.0, 0.0, 5.0)]);
+        let polygons: Vec<Polygon<f64>> = feature_polygons(&[feature(Some(multi_polygon))]).unwrap();
+        assert_eq!(polygons.len(), 1);
+    }
+
+    #[test]
+    fn test_unsupported_geometry_is_rejected() {
+        let point: Value = Value::Point(vec![0.0, 0.0]);
+        assert!(matches!(feature_polygons(&[feature(Some(point))]), Err(GeoTilerError::GeoJsonError { .. })));
+    }
+
+    #[test]
+    fn test_unclosed_geojson_rings_are_closed_and_clipped() {
+        let unclosed: PolygonType = vec![
+            vec![vec![2.0, 2.0], vec![8.0, 2.0], vec![8.0, 8.0], vec![2.0, 8.0]],
+            vec![vec![4.0, 4.0], vec![4.0, 5.0], vec![5.0, 5.0], vec![5.0, 4.0]],
+        ];
+        assert_ne!(unclosed[0].first(), unclosed[0].last());
+
+        let polygons: Vec<Polygon<f64>> = feature_polygons(&[feature(Some(Value::Polygon(unclosed)))]).unwrap();
+        assert_eq!(polygons.len(), 1);
+        let mut polygon: Polygon<f64> = polygons[0].clone();
+        assert!(polygon.exterior().is_closed());
+        assert_eq!(polygon.exterior().0.len(), 5);
+        assert!(polygon.interiors()[0].is_closed());
+
+        // the rings are already closed, so closing them again changes nothing
+        crate::ensure_closed_ring(&mut polygon);
+        assert_eq!(polygon, polygons[0]);
+
+        let mut grid: Vec<Tile> = generate_grid(10).unwrap();
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+        let fragments: Vec<&Polygon<f64>> = grid.iter().flat_map(|tile| &tile.polygons).collect();
+        assert_eq!(fragments.len(), 1);
+        assert!(fragments[0].exterior().is_closed());
+        assert!((crate::planar_polygon_area(fragments[0]) - 35.0).abs() < 1e-9);
+    }
+}
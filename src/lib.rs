@@ -3,24 +3,104 @@ mod geometry;
 mod fibonacci;
 mod tile;
 mod mesh_generator;
+mod simplify;
+mod export;
+mod coords;
+mod import;
 
 
-pub use errors::GeoTilerError;
+pub use errors::{GeoTilerError, ErrorSource};
 pub use geometry::{
     ll_to_cartesian, 
+    ll_to_cartesian_with_tolerance,
+    cartesian_to_ll,
     stereographic_projection,
+    stereographic_projection_clamped,
     rotate_points_to_south_pole,
-    densify_edges
+    slerp,
+    densify_edges,
+    densify_edges_with_distance,
+    densify_edges_near_grid,
+    validate_polygon_coordinates,
+    ensure_closed_ring,
+    ensure_exterior_is_largest,
+    planar_polygon_area,
+    has_zero_area,
+    fragment_angular_extent,
+    polygon_exceeds_hemisphere,
+    spherical_perimeter,
+    DensifyDistance
 };
-pub use fibonacci::fibonacci_sphere;
+pub use fibonacci::{fibonacci_sphere, fibonacci_sphere_with_angle, fibonacci_sphere_jittered, fibonacci_count_for_density};
 pub use tile::{
     generate_grid,
+    grid_iter,
+    generate_grid_filtered,
+    generate_grid_with_caps,
+    generate_grid_with_origin,
     clip_polygon_to_tiles,
+    clip_polygon_to_tiles_with_distance,
+    clip_polygon_to_tiles_checked,
+    clip_geometry_to_tiles,
+    clip_polygon_to_subgrid,
+    split_polygon_by_grid,
+    clip_roundtrip_area_error,
+    clip_polygon_to_tiles_reporting,
+    clip_polygon_tracking_remainder,
     clamp_polygons,
+    clamp_polygons_with_margin,
+    infer_grid_step,
+    validate_grid_tessellation,
+    merge_grids,
+    clear_grid,
+    transform_grid_coordinates,
+    filter_fragments_by_area,
+    densify_tile_boundaries,
+    check_boundary_consistency,
+    tiles_in_bbox,
+    tile_indices_in_bbox,
+    neighbor_indices,
+    nearest_fragment,
+    Grid,
     Tile
 };
+#[cfg(feature = "parallel")]
+pub use tile::clip_features_parallel;
 pub use mesh_generator::{
     generate_polygon_feature_mesh,
+    generate_polygon_feature_mesh_boundary_only,
+    generate_polygon_feature_mesh_with_points,
+    generate_polygon_feature_mesh_with_config,
+    generate_polygon_feature_mesh_debug,
+    generate_lod_meshes,
     get_mesh_points,
-    PolygonMeshData
+    get_mesh_points_with_config,
+    count_interior_points,
+    project_mesh_points,
+    mesh_all_tiles_timed,
+    mesh_grid_collect_errors,
+    mesh_tile_combined,
+    merge_grid_mesh,
+    dedup_triangles,
+    PolygonMeshData,
+    TilerConfig,
+    ContainmentMode,
+    Pole
 };
+pub use simplify::{simplify_polygon, repair_polygon};
+pub use coords::dms_to_decimal;
+pub use import::feature_polygons;
+pub use export::{
+    write_ply,
+    write_tile_binary,
+    read_tile_binary,
+    round_coordinates,
+    save_grid_template,
+    load_grid_template,
+    MeshMetadata,
+    MeshProjection,
+    CoordinateUnits,
+    TileMeshFile
+};
+#[cfg(feature = "image")]
+pub use export::render_coverage_png;
\ No newline at end of file
@@ -1,118 +1,182 @@
 use std::{env, fs::{self, File, OpenOptions}, path::Path, io::Write};
-use geo::{coord, Coord, LineString, Polygon};
-use geojson::{FeatureCollection, GeoJson, Geometry, PolygonType, Value};
+use geo::Polygon;
+use geojson::{FeatureCollection, GeoJson};
 use geo_tiler::{
         Tile, 
         PolygonMeshData, 
         generate_grid, 
         clip_polygon_to_tiles, 
         generate_polygon_feature_mesh, 
-        clamp_polygons
+        clamp_polygons,
+        simplify_polygon,
+        round_coordinates,
+        repair_polygon,
+        has_zero_area,
+        feature_polygons,
+        MeshMetadata
     };
 
 
 
+/// Optional processing steps selected on the command line.
+#[derive(Default)]
+struct Options {
+    simplify_epsilon: Option<f64>,
+    precision: Option<u32>,
+    repair: bool,
+    skip_zero_area: bool,
+}
+
 fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+
     /* get file path from args */
     let args: Vec<String> = env::args().collect();
 
-    if args.len() != 3 {
-        eprintln!("Usage: {} <file_path> <directory_path>", args[0]);
+    let mut positional: Vec<&str> = Vec::new();
+    let mut options: Options = Options::default();
+    let mut i: usize = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--simplify" => {
+                let epsilon: f64 = args.get(i + 1).and_then(|arg| arg.parse().ok()).unwrap_or_else(|| {
+                    eprintln!("--simplify expects a numeric tolerance in degrees");
+                    std::process::exit(1);
+                });
+                options.simplify_epsilon = Some(epsilon);
+                i += 2;
+            }
+            "--precision" => {
+                let decimals: u32 = args.get(i + 1).and_then(|arg| arg.parse().ok()).unwrap_or_else(|| {
+                    eprintln!("--precision expects a non-negative number of decimal places");
+                    std::process::exit(1);
+                });
+                options.precision = Some(decimals);
+                i += 2;
+            }
+            "--repair" => {
+                options.repair = true;
+                i += 1;
+            }
+            "--skip-zero-area" => {
+                options.skip_zero_area = true;
+                i += 1;
+            }
+            arg => {
+                positional.push(arg);
+                i += 1;
+            }
+        }
+    }
+
+    if positional.len() != 2 {
+        eprintln!("Usage: {} <file_path> <directory_path> [--simplify <epsilon>] [--precision <decimals>] [--repair] [--skip-zero-area]", args[0]);
         std::process::exit(1);
     }
-    let file_path: &str = &args[1];
-    let dir_path: &str = &args[2];
 
+    if let Err(message) = run(positional[0], positional[1], &options) {
+        eprintln!("{}", message);
+        std::process::exit(1);
+    }
+}
 
+/// Tiles the polygons of a GeoJSON file and writes the meshes of every tile to `dir_path`.
+///
+/// Returns the number of tile files written, which is zero when the file holds no features.
+fn run(file_path: &str, dir_path: &str, options: &Options) -> Result<usize, String> {
     /* parse geojson */
-    let file_content: String = fs::read_to_string(file_path).unwrap_or_else(|e| {
-        eprintln!("Failed to read GeoJSON file: {}", e);
-        std::process::exit(1);
-    });
-    let geojson: GeoJson = file_content.parse().unwrap_or_else(|e| {
-        eprintln!("Failed to parse GeoJson from file: {}", e);
-        std::process::exit(1);
-    });
-    let features: FeatureCollection = FeatureCollection::try_from(geojson).unwrap_or_else(|e| {
-        eprintln!("Failed to collect features from parsed GeoJson: {}", e);
-        std::process::exit(1);
-    });
+    let file_content: String = fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read GeoJSON file: {}", e))?;
+    let geojson: GeoJson = file_content.parse()
+        .map_err(|e| format!("Failed to parse GeoJson from file: {}", e))?;
+    let features: FeatureCollection = FeatureCollection::try_from(geojson)
+        .map_err(|e| format!("Failed to collect features from parsed GeoJson: {}", e))?;
+
+    if features.features.is_empty() {
+        log::warn!("{} contains no features, no tile files were written", file_path);
+        return Ok(0);
+    }
 
 
     /* generate grid */
-    let mut grid: Vec<Tile> = generate_grid(20).unwrap_or_else(|e| {
-        eprintln!("Failed to generate grid: {}", e);
-        std::process::exit(1);
-    });
+    let mut grid: Vec<Tile> = generate_grid(20)
+        .map_err(|e| format!("Failed to generate grid: {}", e))?;
 
 
     /* clip every polygon */
-    for feature in features {
-        let geometry: &Geometry = feature.geometry.as_ref().unwrap_or_else(|| {
-            eprintln!("Feature without a geometry: {}", feature);
-            std::process::exit(1);
-        });
-        let polygon: &PolygonType = match &geometry.value {
-            Value::Polygon(polygon) => polygon,
-            _ => {
-                eprintln!("Expected a Polygon as a geometry");
-                std::process::exit(1);
-            }
-        };
-        let outer_ring: Vec<Coord<f64>> = polygon[0]
-            .iter()
-            .map(|pos| coord! {x: pos[0], y: pos[1]})
-            .collect();
-
-        let polygon: Polygon = Polygon::new(LineString::new(outer_ring), vec![]);
-        
-        clip_polygon_to_tiles(&mut grid, &polygon).unwrap_or_else(|e| {
-            eprintln!("Failed to clip polygon to grid: {}", e);
-            std::process::exit(1);
-        });
+    let polygons: Vec<Polygon> = feature_polygons(&features.features).map_err(|e| e.to_string())?;
+
+    let mut skipped_polygons: usize = 0;
+    for mut polygon in polygons {
+        if let Some(epsilon) = options.simplify_epsilon {
+            polygon = simplify_polygon(&polygon, epsilon);
+        }
+
+        if options.skip_zero_area && has_zero_area(&polygon) {
+            skipped_polygons += 1;
+            continue;
+        }
+
+        clip_polygon_to_tiles(&mut grid, &polygon)
+            .map_err(|e| format!("Failed to clip polygon to grid: {}", e))?;
+    }
+    if skipped_polygons > 0 {
+        log::warn!("Skipped {} polygon(s) with zero area", skipped_polygons);
     }
     clamp_polygons(&mut grid); // needed for clipping floating number math inaccuracies
 
     /* obtain 3D coordinates for these polygons and save them */
+    let metadata_string: String = serde_json::to_string(&MeshMetadata::default())
+        .map_err(|e| format!("Failed to serialize mesh metadata: {}", e))?;
+
+    let tile_count: usize = grid.len();
     for tile in grid {
         let file_name: String = get_tile_file_name(&tile);
         let path: String = format!("{}/{}", dir_path, file_name);
 
         if let Some(parent) = Path::new(&path).parent() {
-            std::fs::create_dir_all(parent).unwrap_or_else(|e| {
-                eprintln!("Failed to create directories: {}", e);
-                std::process::exit(1);
-            });
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directories: {}", e))?;
         }
 
         let mut file: File = OpenOptions::new()
             .create(true)
-            .append(true)
+            .write(true)
+            .truncate(true)
             .open(&path)
-            .unwrap_or_else(|e| {
-                eprintln!("Failed to open file: {}", e);
-                std::process::exit(1);
-            });
-
-        writeln!(file, "[\n").unwrap();
-        for (i, polygon) in tile.polygons.iter().enumerate() {
-            let mesh_data: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap_or_else(|e| {
-                eprintln!("Failed to generate mesh from polygon: {}\n{}", e, &tile);
-                std::process::exit(1);
-            });
-            let polygon_string: String = serde_json::to_string(&mesh_data).unwrap_or_else(|e| {
-                eprintln!("Failed to serialize polygon: {}", e);
-                std::process::exit(1);
-            });
+            .map_err(|e| format!("Failed to open file: {}", e))?;
+
+        let mut fragments: Vec<Polygon> = tile.polygons.clone();
+        if options.repair {
+            let mut repaired: Vec<Polygon> = Vec::new();
+            for polygon in &fragments {
+                repaired.extend(repair_polygon(polygon)
+                    .map_err(|e| format!("Failed to repair polygon: {}\n{}", e, &tile))?);
+            }
+            fragments = repaired;
+        }
+
+        let write_error = |e: std::io::Error| format!("Failed to write {}: {}", path, e);
+        writeln!(file, "{{\n\t\"metadata\": {},\n\t\"meshes\": [\n", metadata_string).map_err(write_error)?;
+        for (i, polygon) in fragments.iter().enumerate() {
+            let mut mesh_data: PolygonMeshData = generate_polygon_feature_mesh(&polygon)
+                .map_err(|e| format!("Failed to generate mesh from polygon: {}\n{}", e, &tile))?;
+            if let Some(decimals) = options.precision {
+                round_coordinates(&mut mesh_data, decimals);
+            }
+            let polygon_string: String = serde_json::to_string(&mesh_data)
+                .map_err(|e| format!("Failed to serialize polygon: {}", e))?;
             
-            if i == tile.polygons.len() - 1 {
-                writeln!(file, "\t{}", polygon_string).unwrap();
+            if i == fragments.len() - 1 {
+                writeln!(file, "\t\t{}", polygon_string).map_err(write_error)?;
             } else {
-                writeln!(file, "\t{},", polygon_string).unwrap();
+                writeln!(file, "\t\t{},", polygon_string).map_err(write_error)?;
             }
         }
-        writeln!(file, "\n]").unwrap();
+        writeln!(file, "\n\t]\n}}").map_err(write_error)?;
     }
+
+    Ok(tile_count)
 }
 
 fn get_tile_file_name(tile: &Tile) -> String {
@@ -125,4 +189,88 @@ fn get_tile_file_name(tile: &Tile) -> String {
     name.push_str(".json");
 
     name
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_tiler::TileMeshFile;
+
+    #[test]
+    fn test_empty_feature_collection_writes_no_files() {
+        let dir: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_empty_{}", std::process::id()));
+        let input: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_empty_{}.geojson", std::process::id()));
+        fs::write(&input, r#"{"type": "FeatureCollection", "features": []}"#).unwrap();
+
+        let written: Result<usize, String> = run(input.to_str().unwrap(), dir.to_str().unwrap(), &Options::default());
+        fs::remove_file(&input).unwrap();
+
+        assert_eq!(written, Ok(0));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_null_geometry_feature_is_skipped_and_polygon_is_tiled() {
+        let dir: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_null_geometry_{}", std::process::id()));
+        let input: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_null_geometry_{}.geojson", std::process::id()));
+        fs::write(&input, r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {}, "geometry": null},
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[5, -5], [15, -5], [15, 5], [5, 5], [5, -5]]]}}
+        ]}"#).unwrap();
+
+        let written: Result<usize, String> = run(input.to_str().unwrap(), dir.to_str().unwrap(), &Options::default());
+        let tile_file: Result<String, std::io::Error> = fs::read_to_string(dir.join("0,-10;20,-10;20,10;0,10;0,-10.json"));
+        let empty_tile_file: Result<String, std::io::Error> = fs::read_to_string(dir.join("20,-10;40,-10;40,10;20,10;20,-10.json"));
+        fs::remove_file(&input).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(written, Ok(18 * 9));
+        let tile: serde_json::Value = serde_json::from_str(&tile_file.unwrap()).unwrap();
+        assert_eq!(tile["meshes"].as_array().unwrap().len(), 1);
+        let empty_tile: serde_json::Value = serde_json::from_str(&empty_tile_file.unwrap()).unwrap();
+        assert!(empty_tile["meshes"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_zero_area_polygon_fails_unless_skipped() {
+        let dir: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_zero_area_{}", std::process::id()));
+        let input: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_zero_area_{}.geojson", std::process::id()));
+        fs::write(&input, r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [5, 5], [10, 10], [0, 0]]]}},
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[5, -5], [15, -5], [15, 5], [5, 5], [5, -5]]]}}
+        ]}"#).unwrap();
+
+        let failed: Result<usize, String> = run(input.to_str().unwrap(), dir.to_str().unwrap(), &Options::default());
+        let skipping: Options = Options { skip_zero_area: true, ..Options::default() };
+        let written: Result<usize, String> = run(input.to_str().unwrap(), dir.to_str().unwrap(), &skipping);
+        let tile_file: Result<String, std::io::Error> = fs::read_to_string(dir.join("0,-10;20,-10;20,10;0,10;0,-10.json"));
+        fs::remove_file(&input).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(failed, Err("Failed to clip polygon to grid: Invalid polygon error: Polygon has zero area".to_string()));
+        assert_eq!(written, Ok(18 * 9));
+        let tile: serde_json::Value = serde_json::from_str(&tile_file.unwrap()).unwrap();
+        assert_eq!(tile["meshes"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_rerun_overwrites_tile_files() {
+        let dir: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_rerun_{}", std::process::id()));
+        let input: std::path::PathBuf = env::temp_dir().join(format!("geo_tiler_rerun_{}.geojson", std::process::id()));
+        fs::write(&input, r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[5, -5], [15, -5], [15, 5], [5, 5], [5, -5]]]}}
+        ]}"#).unwrap();
+
+        let first: Result<usize, String> = run(input.to_str().unwrap(), dir.to_str().unwrap(), &Options::default());
+        let second: Result<usize, String> = run(input.to_str().unwrap(), dir.to_str().unwrap(), &Options::default());
+        let tile_file: Result<String, std::io::Error> = fs::read_to_string(dir.join("0,-10;20,-10;20,10;0,10;0,-10.json"));
+        fs::remove_file(&input).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, Ok(18 * 9));
+        assert_eq!(second, Ok(18 * 9));
+        let tile: TileMeshFile = serde_json::from_str(&tile_file.unwrap()).unwrap();
+        assert_eq!(tile.metadata, MeshMetadata::default());
+        assert_eq!(tile.meshes.len(), 1);
+    }
+}
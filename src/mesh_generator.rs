@@ -1,19 +1,146 @@
 use d3_geo_rs::polygon_contains::polygon_contains;
-use geo::{coord, Coord, HasDimensions, LineString, Polygon};
+use geo::{coord, Contains, Coord, HasDimensions, LineString, Polygon, Winding};
+use geo::orient::{Direction, Orient};
 use ghx_constrained_delaunay::{
-    constrained_triangulation::ConstrainedTriangulationConfiguration, constrained_triangulation_from_2d_vertices, types::{Edge, Vertex2d}, Triangulation
+    constrained_triangulation::ConstrainedTriangulationConfiguration, constrained_triangulation_from_2d_vertices, 
+    triangulation::TriangulationConfiguration, triangulation_from_2d_vertices, types::{Edge, Vertex2d}, Triangulation
 };
 use crate::{
     fibonacci_sphere, 
+    fibonacci_sphere_jittered, 
+    cartesian_to_ll, 
+    ensure_closed_ring,
     ll_to_cartesian, 
-    rotate_points_to_south_pole, 
-    stereographic_projection, 
-    GeoTilerError
+    has_zero_area,
+    polygon_exceeds_hemisphere,
+    stereographic_projection_clamped, 
+    validate_polygon_coordinates,
+    GeoTilerError,
+    Tile
 };
-use serde::{Serialize};
+use crate::geometry::{mean_point, rotate_points_to_south_pole_about, spherical_polygon_centroid};
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
 
 
 const DEFAULT_FIBONACCI_POINT_COUNT: usize = 3000;
+const DEFAULT_BIN_VERTEX_DENSITY_POWER: f64 = 1.0;
+const DEFAULT_TRIANGULATION_ATTEMPTS: usize = 3;
+
+/// Maximum offset applied to interior points when retrying a failed triangulation,
+/// as a fraction of the average spacing between Fibonacci points.
+const TRIANGULATION_RETRY_JITTER: f64 = 0.1;
+
+/// Minimum distance along the z axis kept between a rotated mesh point and the projection pole,
+/// from which the stereographic projection is singular.
+const PROJECTION_POLE_Z_GAP: f64 = 1e-6;
+
+/// Distance on the unit sphere under which two mesh vertices are welded into one.
+const WELD_EPSILON: f64 = 1e-9;
+
+/// Tolerance under which mesh vertices are considered to lie on a single line.
+const COLLINEARITY_EPSILON: f64 = 1e-12;
+
+/// Distance in degrees under which consecutive boundary coordinates are considered duplicates.
+const DEDUP_EPSILON: f64 = 1e-9;
+
+/// Point-in-polygon test used to select the interior Fibonacci points of a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContainmentMode {
+    /// Treats the polygon edges as great-circle arcs on the sphere. This is correct for
+    /// polygons of any size and is the default.
+    #[default]
+    Spherical,
+
+    /// Treats the polygon as planar in longitude/latitude space. This is faster and gives
+    /// the same result as `Spherical` for small polygons, but diverges for large polygons
+    /// whose edges bow noticeably away from straight lon/lat lines.
+    Planar,
+}
+
+/// Pole that mesh points are rotated to before being stereographically projected from the
+/// opposite pole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pole {
+    /// Rotates the points to the south pole and projects them from the north pole. This is the
+    /// default.
+    #[default]
+    South,
+
+    /// Rotates the points to the north pole and projects them from the south pole.
+    North,
+}
+
+/// Configuration options for mesh generation.
+///
+/// Use `TilerConfig::default()` to get the settings used by [`generate_polygon_feature_mesh`]
+/// and override individual fields as needed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TilerConfig {
+    /// Controls how finely the projected points are binned before constrained Delaunay
+    /// triangulation. Points are sorted into roughly `n^bin_vertex_density_power` bins, `n`
+    /// being the number of points, so that consecutive insertions are spatially close.
+    ///
+    /// Higher values create more, smaller bins, which speeds up point location for very large
+    /// fragments at the cost of more binning overhead. Lower values reduce that overhead for
+    /// small fragments. The value only affects triangulation speed and the internal insertion
+    /// order, never the set of mesh vertices. Defaults to `1.0`.
+    pub bin_vertex_density_power: f64,
+
+    /// Rejects polygons whose coordinates look like they were given in radians instead of
+    /// degrees. When enabled, a polygon whose exterior lies entirely within
+    /// [-π, π] × [-π/2, π/2] produces a `MeshGenerationError`. Genuine polygons of a few
+    /// degrees around (0, 0) also trip this check, so it is disabled by default.
+    pub strict_units: bool,
+
+    /// Point-in-polygon test used to select interior Fibonacci points. Defaults to
+    /// [`ContainmentMode::Spherical`].
+    pub containment_mode: ContainmentMode,
+
+    /// Maximum number of triangulation attempts. When constrained Delaunay triangulation fails,
+    /// typically because points became nearly coincident after projection, it is retried with
+    /// interior Fibonacci points slightly jittered using a different seed on each attempt.
+    /// Boundary points are never moved. A value of 0 or 1 disables retries. Defaults to `3`.
+    pub triangulation_attempts: usize,
+
+    /// Forces the polygon boundary into the mesh as constrained edges. When disabled, the boundary
+    /// vertices are left out and only the interior Fibonacci points are triangulated with an
+    /// unconstrained Delaunay triangulation, so the mesh boundary is the convex hull of the
+    /// interior points (in projected space) rather than the polygon outline. This is useful for
+    /// point-cloud style visualizations. Defaults to `true`.
+    pub constrain_boundary: bool,
+
+    /// Maximum number of vertices in a generated mesh. When the boundary and interior points
+    /// together exceed this limit, the interior Fibonacci points are decimated with a uniform
+    /// stride, keeping every k-th point in Fibonacci order, which preserves an even spread over
+    /// the polygon. Boundary vertices are never removed, so a boundary longer than the limit
+    /// produces a mesh without interior points that still exceeds it. Defaults to `None` (no limit).
+    pub max_vertices: Option<usize>,
+
+    /// Pole the mesh points are rotated to before the stereographic projection, which is taken
+    /// from the opposite pole. The projected plane of [`Pole::North`] is the mirror image of the
+    /// one of [`Pole::South`], so the triangulation comes out wound in the opposite direction;
+    /// the triangles are rewound afterwards so that they face away from the center of the sphere
+    /// with either pole. Defaults to [`Pole::South`].
+    pub projection_pole: Pole,
+}
+
+impl Default for TilerConfig {
+    fn default() -> Self {
+        TilerConfig {
+            bin_vertex_density_power: DEFAULT_BIN_VERTEX_DENSITY_POWER,
+            strict_units: false,
+            containment_mode: ContainmentMode::default(),
+            triangulation_attempts: DEFAULT_TRIANGULATION_ATTEMPTS,
+            constrain_boundary: true,
+            max_vertices: None,
+            projection_pole: Pole::default(),
+        }
+    }
+}
 
 /// Represents the geometric data for a triangulated polygon mesh on a sphere.
 ///
@@ -27,14 +154,272 @@ const DEFAULT_FIBONACCI_POINT_COUNT: usize = 3000;
 ///
 /// * `triangles` - Triangle indices for the mesh, flattened as [i1, i2, i3, j1, j2, j3, ...].
 ///   Each consecutive triplet of indices defines one triangle by referencing vertices in the
-///   `vertices` field.
-#[derive(Debug, Clone, Serialize)]
+///   `vertices` field. The mesh generators wind every triangle counter-clockwise as seen from
+///   outside the sphere.
+///
+/// * `boundary_indices` - Indices of the vertices lying on the polygon's rings, as opposed to the
+///   interior Fibonacci points. It is not serialized, and is left empty when deserializing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PolygonMeshData {
     /// 3D points forming the mesh (x, y, z coordinates)
     pub vertices: Vec<(f64, f64, f64)>,
     
     /// Triangle indices for the mesh, flattened as [i1, i2, i3, j1, j2, j3, ...]
     pub triangles: Vec<u32>,
+
+    /// Indices of the boundary vertices, in ascending order
+    #[serde(skip)]
+    pub boundary_indices: Vec<u32>,
+}
+
+impl PolygonMeshData {
+    /// Returns the indices of the vertices lying on the boundary of the meshed polygon.
+    ///
+    /// For meshes from [`generate_polygon_feature_mesh`] these are the points of the outer ring,
+    /// closing point included, followed by those of each hole, which come first in `vertices`.
+    /// Interior Fibonacci points are never included, so the boundary vertices can be matched
+    /// against those of an adjacent mesh when stitching meshes together. A mesh triangulated
+    /// without its boundary, see [`TilerConfig::constrain_boundary`], has no boundary vertices.
+    pub fn boundary_vertex_indices(&self) -> &[u32] {
+        &self.boundary_indices
+    }
+
+    /// Returns the number of triangles in the mesh.
+    pub fn triangle_count(&self) -> usize {
+        debug_assert!(self.triangles.len().is_multiple_of(3), "Triangle indices length must be a multiple of 3");
+
+        self.triangles.len() / 3
+    }
+
+    /// Returns an iterator over the triangles of the mesh, each yielded as the three
+    /// vertex indices `[i1, i2, i3]` referencing the `vertices` field.
+    pub fn triangle_iter(&self) -> impl Iterator<Item = [u32; 3]> + '_ {
+        debug_assert!(self.triangles.len().is_multiple_of(3), "Triangle indices length must be a multiple of 3");
+
+        self.triangles
+            .chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+    }
+
+    /// Returns the vertex positions as a flat `f32` buffer, ready to be uploaded to a GPU vertex
+    /// buffer.
+    ///
+    /// The layout is `[x0, y0, z0, x1, y1, z1, ...]`, three tightly packed values per vertex in
+    /// the order of `vertices`, so the indices returned by [`indices_u32`](Self::indices_u32)
+    /// refer to the `i`-th group of three values. Coordinates are rounded to the nearest `f32`,
+    /// which keeps about 7 significant digits.
+    pub fn positions_f32(&self) -> Vec<f32> {
+        self.vertices.iter()
+            .flat_map(|&(x, y, z)| [x as f32, y as f32, z as f32])
+            .collect()
+    }
+
+    /// Returns the triangle indices as a flat slice, ready to be uploaded to a GPU index buffer.
+    ///
+    /// The layout is `[i1, i2, i3, j1, j2, j3, ...]`, three indices per triangle referencing the
+    /// vertices of [`positions_f32`](Self::positions_f32).
+    pub fn indices_u32(&self) -> &[u32] {
+        &self.triangles
+    }
+
+    /// Returns the positions of the three vertices of the `i`-th triangle of the mesh.
+    ///
+    /// Unlike indexing `triangles` and `vertices` by hand, this never panics, which makes it safe
+    /// to use on meshes whose indices have not been validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - Index of the triangle, in the range `0..self.triangle_count()`
+    ///
+    /// # Returns
+    ///
+    /// * `Some([(f64, f64, f64); 3])` - The triangle's vertex positions, in index order
+    /// * `None` - If `i` is out of range or the triangle references a vertex that does not exist
+    pub fn triangle(&self, i: usize) -> Option<[(f64, f64, f64); 3]> {
+        let start: usize = i.checked_mul(3)?;
+        let corners: &[u32] = self.triangles.get(start..start.checked_add(3)?)?;
+
+        Some([
+            *self.vertices.get(corners[0] as usize)?,
+            *self.vertices.get(corners[1] as usize)?,
+            *self.vertices.get(corners[2] as usize)?,
+        ])
+    }
+
+    /// Computes the area of the `i`-th triangle of the mesh.
+    ///
+    /// The area is that of the flat 3D triangle, half the magnitude of the cross product of two
+    /// of its edges, in units of the unit sphere's radius squared.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - Index of the triangle, in the range `0..self.triangle_count()`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of range or the triangle references a vertex that does not exist.
+    pub fn triangle_area(&self, i: usize) -> f64 {
+        let corners: &[u32] = &self.triangles[i * 3..i * 3 + 3];
+        let [a, b, c]: [Vector3<f64>; 3] = [corners[0], corners[1], corners[2]].map(|index| {
+            let (x, y, z) = self.vertices[index as usize];
+            Vector3::new(x, y, z)
+        });
+
+        (b - a).cross(&(c - a)).magnitude() / 2.0
+    }
+
+    /// Computes the total surface area of the mesh by summing the area of every triangle.
+    ///
+    /// Since the vertices lie on the unit sphere, this approaches the spherical area of the
+    /// polygon (in steradians) as the mesh gets finer, and slightly underestimates it otherwise.
+    pub fn total_area(&self) -> f64 {
+        (0..self.triangle_count())
+            .map(|i| self.triangle_area(i))
+            .sum()
+    }
+
+    /// Computes a bounding sphere of the mesh, suitable for frustum culling.
+    ///
+    /// The center is the average of the vertex positions, which lies inside the unit sphere, and the
+    /// radius is the largest distance from that center to any vertex. The result is not the minimal
+    /// bounding sphere, but it is cheap to compute and always encloses every vertex.
+    ///
+    /// # Returns
+    ///
+    /// * `((f64, f64, f64), f64)` - The center and radius of the sphere, or a zero-radius sphere at the
+    ///   origin if the mesh has no vertices
+    pub fn bounding_sphere(&self) -> ((f64, f64, f64), f64) {
+        let center: Vector3<f64> = mean_point(&self.vertices);
+
+        let radius: f64 = self.vertices.iter()
+            .map(|&(x, y, z)| (Vector3::new(x, y, z) - center).magnitude())
+            .fold(0.0, f64::max);
+
+        ((center.x, center.y, center.z), radius)
+    }
+
+    /// Converts the triangles of the mesh to geographic coordinates.
+    ///
+    /// Each vertex is converted back to (longitude, latitude) in decimal degrees with
+    /// [`cartesian_to_ll`](crate::cartesian_to_ll), and the vertices are grouped per triangle in the
+    /// same order as [`triangle_iter`](Self::triangle_iter).
+    ///
+    /// Longitudes are always reported in [-180, 180], so a triangle crossing the antimeridian has
+    /// vertices on both sides of it (for example 179° and -179°). Consumers working in planar
+    /// lon/lat space must unwrap such triangles, which can be detected by a longitude span greater
+    /// than 180°.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<[(f64, f64); 3]>)` - The (longitude, latitude) corners of every triangle
+    /// * `Err(GeoTilerError)` - A `MeshGenerationError` if a triangle references a vertex that does
+    ///   not exist, or a `CoordinateFormatError` if a vertex cannot be converted
+    pub fn to_lonlat_triangles(&self) -> Result<Vec<[(f64, f64); 3]>, GeoTilerError> {
+        let vertices_ll: Vec<(f64, f64)> = self.vertices.iter()
+            .map(|&vertex| cartesian_to_ll(vertex))
+            .collect::<Result<_, _>>()?;
+
+        self.triangle_iter()
+            .map(|triangle| {
+                let mut corners: [(f64, f64); 3] = [(0.0, 0.0); 3];
+                for (corner, index) in corners.iter_mut().zip(triangle) {
+                    *corner = *vertices_ll.get(index as usize).ok_or_else(|| GeoTilerError::MeshGenerationError(
+                        format!("Triangle references vertex {} but the mesh has {} vertices", index, vertices_ll.len())
+                    ))?;
+                }
+
+                Ok(corners)
+            })
+            .collect()
+    }
+
+    /// Projects every vertex of the mesh to 2D with a user-supplied projection.
+    ///
+    /// The returned points are in the same order as `vertices`, so the `triangles` indices of the
+    /// mesh apply to them unchanged. For example, passing [`cartesian_to_ll`](crate::cartesian_to_ll)
+    /// yields the vertices in (longitude, latitude), and composing it with a Web Mercator formula
+    /// yields map coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Projection mapping a 3D vertex to a 2D point
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(f64, f64)>)` - The projected vertices, one per vertex of the mesh
+    /// * `Err(GeoTilerError)` - The first error returned by `f`
+    pub fn reproject(&self, f: impl Fn((f64, f64, f64)) -> Result<(f64, f64), GeoTilerError>) -> Result<Vec<(f64, f64)>, GeoTilerError> {
+        self.vertices.iter()
+            .map(|&vertex| f(vertex))
+            .collect()
+    }
+
+    /// Computes the centroid of the mesh projected back onto the unit sphere.
+    ///
+    /// The vertex positions are averaged and the result is renormalized so that it lies on the
+    /// unit sphere, which makes it suitable for placing labels over the mesh.
+    ///
+    /// # Returns
+    ///
+    /// * `(f64, f64, f64)` - The centroid on the unit sphere, or the origin `(0, 0, 0)` if the mesh
+    ///   has no vertices or its vertices average out to the center of the sphere
+    pub fn spherical_centroid(&self) -> (f64, f64, f64) {
+        let center: Vector3<f64> = mean_point(&self.vertices);
+
+        match center.try_normalize(f64::EPSILON) {
+            Some(center) => (center.x, center.y, center.z),
+            None => (0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Checks that the mesh is usable, typically right before exporting it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the mesh has vertices, whole triangles referencing existing vertices, and
+    ///   vertices which do not all lie on a single line
+    /// * `Err(GeoTilerError::TriangulationError)` - An error describing the first problem found:
+    ///   - The mesh has no vertices
+    ///   - The length of `triangles` is not a multiple of 3
+    ///   - A triangle references a vertex that does not exist
+    ///   - Every vertex lies on a single line, so no triangle can have a non-zero area
+    pub fn validate(&self) -> Result<(), GeoTilerError> {
+        if self.vertices.is_empty() {
+            return Err(GeoTilerError::TriangulationError("Mesh has no vertices".to_string()));
+        }
+
+        if !self.triangles.len().is_multiple_of(3) {
+            return Err(GeoTilerError::TriangulationError(
+                format!("Mesh has {} triangle indices, which is not a multiple of 3", self.triangles.len())
+            ));
+        }
+
+        if let Some(index) = self.triangles.iter().find(|&&index| index as usize >= self.vertices.len()) {
+            return Err(GeoTilerError::TriangulationError(
+                format!("Triangle references vertex {} but the mesh has {} vertices", index, self.vertices.len())
+            ));
+        }
+
+        if let Some(index) = self.boundary_indices.iter().find(|&&index| index as usize >= self.vertices.len()) {
+            return Err(GeoTilerError::TriangulationError(
+                format!("Boundary references vertex {} but the mesh has {} vertices", index, self.vertices.len())
+            ));
+        }
+
+        let points: Vec<Vector3<f64>> = self.vertices.iter().map(|&(x, y, z)| Vector3::new(x, y, z)).collect();
+        let origin: Vector3<f64> = points[0];
+        let is_collinear: bool = match points.iter().find(|point| (*point - origin).magnitude() > COLLINEARITY_EPSILON) {
+            Some(second) => points.iter().all(|point| (point - origin).cross(&(second - origin)).magnitude() <= COLLINEARITY_EPSILON),
+            None => true,
+        };
+        if is_collinear {
+            return Err(GeoTilerError::TriangulationError(
+                format!("All {} mesh vertices are collinear", self.vertices.len())
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 /// Generates a triangulated 3D mesh from a 2D geographic polygon using constrained Delaunay triangulation.
@@ -47,7 +432,16 @@ pub struct PolygonMeshData {
 /// 5. Returning both the 3D vertices and triangle connectivity information
 ///
 /// The resulting mesh preserves the polygon's boundary as constrained edges while efficiently
-/// triangulating the interior using a mathematically optimal point distribution.
+/// triangulating the interior using a mathematically optimal point distribution. Interior rings
+/// are constrained as well, so holes (and fragments of holed polygons left by clipping) stay
+/// empty in the mesh.
+///
+/// If the triangulation fails, it is retried up to twice with slightly jittered interior
+/// points (see [`TilerConfig::triangulation_attempts`]).
+///
+/// A triangle or quad without holes which is too small to hold any Fibonacci point skips the
+/// triangulation step: its 3 or 4 distinct boundary vertices are triangulated directly as a fan,
+/// producing 1 or 2 triangles facing away from the center of the sphere.
 ///
 /// # Arguments
 ///
@@ -65,61 +459,246 @@ pub struct PolygonMeshData {
 ///   - Coordinate conversion fails (invalid longitude/latitude values)
 ///   - Stereographic projection fails
 ///   - Constrained Delaunay triangulation fails
+///   - The polygon covers more than a hemisphere (see [`polygon_exceeds_hemisphere`])
 pub fn generate_polygon_feature_mesh(polygon: &Polygon) -> Result<PolygonMeshData, GeoTilerError> {
-    let num_points: usize = polygon.exterior().points().len();
+    generate_polygon_feature_mesh_with_config(polygon, &TilerConfig::default())
+}
+
+/// Generates a triangulated 3D mesh from a 2D geographic polygon using the given configuration.
+///
+/// This behaves like [`generate_polygon_feature_mesh`] but lets the caller tune the mesh
+/// generation through a [`TilerConfig`].
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude).
+///   The polygon must have at least 3 boundary points and cannot be empty.
+/// * `config` - The mesh generation settings
+///
+/// # Returns
+///
+/// * `Ok(PolygonMeshData)` - The mesh vertices and flattened triangle indices
+/// * `Err(GeoTilerError)` - Under the same conditions as [`generate_polygon_feature_mesh`], or a
+///   `MeshGenerationError` if `config.strict_units` is set and the coordinates look like radians,
+///   or if `config.constrain_boundary` is unset and fewer than 3 interior points fall inside the
+///   polygon, or an `InvalidPolygonError` if the polygon has zero area (see [`has_zero_area`])
+pub fn generate_polygon_feature_mesh_with_config(polygon: &Polygon, config: &TilerConfig) -> Result<PolygonMeshData, GeoTilerError> {
+    let (mesh_data, _) = generate_projected_mesh(polygon, config)?;
 
-    let mesh_points: Vec<(f64, f64, f64)> = get_mesh_points(polygon)?;
+    Ok(mesh_data)
+}
 
-    // calculate edges for outer ring
-    let mut edges: Vec<Edge> = Vec::with_capacity(num_points);
-    for i in (0..num_points).rev() {
-        let edge: Edge = Edge {
-            from: i as u32,
-            to: ((i + num_points - 1) % num_points) as u32
-        };
-        edges.push(edge);
+/// Runs the full mesh generation of [`generate_polygon_feature_mesh_with_config`] and returns the
+/// mesh along with the 2D projected points it was triangulated from, the `i`-th projected point
+/// corresponding to the `i`-th mesh vertex, or `None` if the polygon was fan triangulated without
+/// being projected.
+fn generate_projected_mesh(polygon: &Polygon, config: &TilerConfig) -> Result<MaybeProjectedMesh, GeoTilerError> {
+    generate_projected_mesh_with(polygon, config, triangulate_configured_points)
+}
+
+/// Runs [`generate_projected_mesh`] with the given triangulation step, called once per attempt
+/// with the mesh points and ring lengths, so that tests can make attempts fail on purpose.
+fn generate_projected_mesh_with(
+    polygon: &Polygon,
+    config: &TilerConfig,
+    mut triangulate: impl FnMut(Vec<(f64, f64, f64)>, &[usize], &TilerConfig) -> Result<ProjectedMesh, GeoTilerError>
+) -> Result<MaybeProjectedMesh, GeoTilerError> {
+    let mut polygon: Polygon = polygon.clone();
+    ensure_closed_ring(&mut polygon);
+    let polygon: &Polygon = &polygon;
+
+    if config.strict_units && looks_like_radians(polygon) {
+        return Err(GeoTilerError::MeshGenerationError(
+            "Polygon coordinates all fall within [-π, π] × [-π/2, π/2]; they are likely in radians but must be in decimal degrees".to_string()
+        ));
     }
 
-    // rotate points to south pole for better stereographic projection
-    let rotated_points: Vec<(f64, f64, f64)> = rotate_points_to_south_pole(&mesh_points)?;
+    ensure_within_hemisphere(polygon)?;
 
-    // do a stereographic projection
-    let mut projected_points: Vec<CoordVertex<f64>> = Vec::new();
-    for point in rotated_points {
-        let projected_point: Coord<f64> = stereographic_projection(point)?;
+    if has_zero_area(polygon) {
+        return Err(GeoTilerError::InvalidPolygonError("Polygon has zero area".to_string()));
+    }
 
-        let projected_point: CoordVertex<f64> = CoordVertex { x: projected_point.x, y: projected_point.y };
-        
-        projected_points.push(projected_point);
+    let ring_lengths: Vec<usize> = boundary_ring_lengths(polygon);
+
+    // a lone triangle or quad with nothing to fill needs no delaunay triangulation
+    if config.constrain_boundary
+        && polygon.interiors().is_empty()
+        && polygon.exterior().0.len() <= 5
+        && count_interior_points_with_config(polygon, config, DEFAULT_FIBONACCI_POINT_COUNT)? == 0
+        && let Some(mesh) = fan_triangulation(&get_boundary_points(polygon)?) {
+        return Ok((mesh, None));
     }
-    
 
-    let config: ConstrainedTriangulationConfiguration = ConstrainedTriangulationConfiguration {
-        bin_vertex_density_power: 1.0,
-    };
+    let mut mesh_points: Vec<(f64, f64, f64)> = get_mesh_points_with_config(polygon, config)?;
 
-    // generate mesh triangles using constrained delaunay triangulation
-    let delaunay_triangles: Triangulation = match constrained_triangulation_from_2d_vertices(&projected_points, &edges, config) {
-        Ok(triangles) => triangles,
-        Err(err) => return Err(GeoTilerError::TriangulationError(format!("Failed to generate triangulation: {}", err)))
-    };
+    let (mesh, projected_points) = triangulate_with_retries(config.triangulation_attempts, |attempt| {
+        if attempt == 0 {
+            return triangulate(std::mem::take(&mut mesh_points), &ring_lengths, config);
+        }
 
-    let flattened_delaunay: Vec<u32> = delaunay_triangles.triangles.into_iter()
-        .flat_map(|triangle| triangle.into_iter())
-        .collect();
-    
-    Ok(PolygonMeshData {
-        vertices: mesh_points,
-        triangles: flattened_delaunay
-    })
+        // perturb the interior points when the exact lattice produces a degenerate projection
+        let fibonacci_points: Vec<Coord<f64>> = fibonacci_sphere_jittered(DEFAULT_FIBONACCI_POINT_COUNT, attempt as u64, TRIANGULATION_RETRY_JITTER)?;
+        let mesh_points: Vec<(f64, f64, f64)> = collect_mesh_points(polygon, config, &fibonacci_points)?;
+        triangulate(mesh_points, &ring_lengths, config)
+    })?;
+
+    Ok((mesh, Some(projected_points)))
+}
+
+/// Calls `triangulate` with attempt numbers 0, 1, 2, ... until it returns something other than a
+/// `TriangulationError` or `attempts` attempts have been made, and returns the last result.
+///
+/// At least one attempt is always made, so an `attempts` of 0 behaves like 1.
+fn triangulate_with_retries<T>(
+    attempts: usize,
+    mut triangulate: impl FnMut(usize) -> Result<T, GeoTilerError>
+) -> Result<T, GeoTilerError> {
+    let mut result: Result<T, GeoTilerError> = triangulate(0);
+
+    for attempt in 1..attempts {
+        match &result {
+            Err(err @ GeoTilerError::TriangulationError(_)) => log::debug!(
+                "Triangulation attempt {} of {} failed ({}), retrying with jittered interior points",
+                attempt, attempts, err
+            ),
+            _ => break,
+        }
+
+        result = triangulate(attempt);
+    }
+
+    result
+}
+
+/// Generates a triangulated 3D mesh from a 2D geographic polygon using only its boundary vertices.
+///
+/// This function skips the Fibonacci sphere interior fill entirely and triangulates the constrained
+/// boundary loop on its own. It is considerably faster than [`generate_polygon_feature_mesh`] and is
+/// well suited to small or simple polygons (such as tiny tile fragments) where interior points add
+/// no visible curvature. Large polygons meshed this way will have long, flat triangles that cut
+/// through the sphere.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude).
+///   The polygon must have at least 3 boundary points and cannot be empty.
+///
+/// # Returns
+///
+/// * `Ok(PolygonMeshData)` - Contains:
+///   - `vertices`: 3D Cartesian coordinates (x, y, z) of the boundary points on the unit sphere
+///   - `triangles`: Flattened triangle indices [i1, i2, i3, j1, j2, j3, ...] referencing the vertices
+///
+/// * `Err(GeoTilerError)` - Returns an error if:
+///   - The polygon is empty or has fewer than 3 boundary points
+///   - Coordinate conversion fails (invalid longitude/latitude values)
+///   - Stereographic projection fails
+///   - Constrained Delaunay triangulation fails
+///   - The polygon covers more than a hemisphere (see [`polygon_exceeds_hemisphere`])
+pub fn generate_polygon_feature_mesh_boundary_only(polygon: &Polygon) -> Result<PolygonMeshData, GeoTilerError> {
+    ensure_within_hemisphere(polygon)?;
+
+    let ring_lengths: Vec<usize> = boundary_ring_lengths(polygon);
+
+    let boundary_points: Vec<(f64, f64, f64)> = get_boundary_points(polygon)?;
+
+    let (mesh_data, _) = triangulate_mesh_points(boundary_points, &ring_lengths, &TilerConfig::default())?;
+
+    Ok(mesh_data)
+}
+
+/// Generates a triangulated 3D mesh from a 2D geographic polygon using caller-provided interior
+/// points instead of the Fibonacci sphere fill.
+///
+/// This suits data that comes with its own sampling, such as the nodes of an elevation grid. The
+/// points lying inside the polygon, and outside of its holes, are added to the boundary points
+/// and triangulated as in [`generate_polygon_feature_mesh`]; the others are ignored. Since the
+/// points are used as given, a failed triangulation is not retried with jittered points.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude).
+///   The polygon must have at least 3 boundary points and cannot be empty.
+/// * `interior_lonlat` - Candidate interior points as (longitude, latitude) pairs in decimal degrees
+///
+/// # Returns
+///
+/// * `Ok(PolygonMeshData)` - The mesh, whose vertices are the boundary points followed by the
+///   interior points inside the polygon, in the order they were given
+/// * `Err(GeoTilerError)` - Under the same conditions as [`generate_polygon_feature_mesh`], or a
+///   `CoordinateRangeError` if any interior point is non-finite or out of range, even one lying
+///   outside the polygon
+pub fn generate_polygon_feature_mesh_with_points(polygon: &Polygon, interior_lonlat: &[(f64, f64)]) -> Result<PolygonMeshData, GeoTilerError> {
+    ensure_within_hemisphere(polygon)?;
+
+    let config: TilerConfig = TilerConfig::default();
+    let ring_lengths: Vec<usize> = boundary_ring_lengths(polygon);
+
+    let mut candidate_points: Vec<Coord<f64>> = Vec::with_capacity(interior_lonlat.len());
+    for &(longitude, latitude) in interior_lonlat {
+        // validate every point up front, including those which end up outside the polygon
+        ll_to_cartesian(longitude, latitude)?;
+        candidate_points.push(coord! {x: longitude.to_radians(), y: latitude.to_radians()});
+    }
+
+    let mesh_points: Vec<(f64, f64, f64)> = collect_mesh_points(polygon, &config, &candidate_points)?;
+    let (mesh_data, _) = triangulate_configured_points(mesh_points, &ring_lengths, &config)?;
+
+    Ok(mesh_data)
+}
+
+/// Generates meshes of a polygon at several levels of detail, one per Fibonacci point count.
+///
+/// Each level is meshed like [`generate_polygon_feature_mesh`], but with its own number of
+/// Fibonacci points spread over the sphere instead of the default 3000. The boundary points are
+/// computed once and shared by every level, so all the meshes start with the same boundary
+/// vertices and only differ by their interior points, which keeps the outlines of the levels
+/// identical when switching between them. A failed triangulation is not retried with jittered
+/// points.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude).
+///   The polygon must have at least 3 boundary points and cannot be empty.
+/// * `lod_point_counts` - The number of Fibonacci points of each level, in any order
+///
+/// # Returns
+///
+/// * `Ok(Vec<PolygonMeshData>)` - One mesh per point count, ordered coarse-to-fine by increasing
+///   point count
+/// * `Err(GeoTilerError)` - Under the same conditions as [`generate_polygon_feature_mesh`] for any
+///   level, or a `FibonacciError` if a point count is 0
+pub fn generate_lod_meshes(polygon: &Polygon, lod_point_counts: &[usize]) -> Result<Vec<PolygonMeshData>, GeoTilerError> {
+    ensure_within_hemisphere(polygon)?;
+
+    let config: TilerConfig = TilerConfig::default();
+    let ring_lengths: Vec<usize> = boundary_ring_lengths(polygon);
+    let boundary_points: Vec<(f64, f64, f64)> = get_boundary_points(polygon)?;
+    let rings: Vec<LineString> = containment_rings(polygon);
+
+    let mut point_counts: Vec<usize> = lod_point_counts.to_vec();
+    point_counts.sort_unstable();
+
+    point_counts.into_iter()
+        .map(|point_count| {
+            let fibonacci_points: Vec<Coord<f64>> = fibonacci_sphere(point_count)?;
+            let mut mesh_points: Vec<(f64, f64, f64)> = boundary_points.clone();
+            push_interior_points(polygon, &rings, &config, &fibonacci_points, &mut mesh_points)?;
+            let (mesh_data, _) = triangulate_configured_points(mesh_points, &ring_lengths, &config)?;
+
+            Ok(mesh_data)
+        })
+        .collect()
 }
 
 /// Generates a set of 3D mesh points from a geographic polygon by combining the polygon's
 /// boundary points with interior points generated using a Fibonacci sphere distribution.
 ///
-/// This function takes an outer ring of a polygon defined by longitude and latitude coordinates,
-/// fills it with points from a Fibonacci sphere distribution, and converts all points to 3D
-/// Cartesian coordinates on a unit sphere.
+/// This function takes the rings of a polygon defined by longitude and latitude coordinates,
+/// fills the area between the outer ring and its holes with points from a Fibonacci sphere
+/// distribution, and converts all points to 3D Cartesian coordinates on a unit sphere.
+/// Consecutive duplicate coordinates of the rings are only included once.
 ///
 /// # Arguments
 ///
@@ -129,9 +708,164 @@ pub fn generate_polygon_feature_mesh(polygon: &Polygon) -> Result<PolygonMeshDat
 /// # Returns
 ///
 /// * `Ok(Vec<(f64, f64, f64)>)` - A vector of 3D Cartesian coordinates representing the mesh points
-///                                (both boundary and interior)
+///                                (the outer ring, then each hole, then the interior points)
 /// * `Err(String)` - An error message if the mesh generation cannot be performed
 pub fn get_mesh_points(polygon: &Polygon) -> Result<Vec<(f64, f64, f64)>, GeoTilerError> {
+    get_mesh_points_with_config(polygon, &TilerConfig::default())
+}
+
+/// Generates a set of 3D mesh points from a geographic polygon using the given configuration.
+///
+/// This behaves like [`get_mesh_points`] but selects interior Fibonacci points with the
+/// containment test chosen in `config.containment_mode`.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude)
+/// * `config` - The mesh generation settings
+///
+/// # Returns
+///
+/// * `Ok(Vec<(f64, f64, f64)>)` - The boundary points followed by the interior points, in 3D
+///   Cartesian coordinates
+/// * `Err(GeoTilerError)` - An error if the mesh points cannot be generated
+pub fn get_mesh_points_with_config(polygon: &Polygon, config: &TilerConfig) -> Result<Vec<(f64, f64, f64)>, GeoTilerError> {
+    let fibonacci_points: Vec<Coord<f64>> = fibonacci_sphere(DEFAULT_FIBONACCI_POINT_COUNT)?;
+
+    collect_mesh_points(polygon, config, &fibonacci_points)
+}
+
+/// Collects the polygon's boundary points followed by the given sphere points which fall inside
+/// the polygon, all converted to 3D Cartesian coordinates.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude)
+/// * `config` - The mesh generation settings
+/// * `candidate_points` - Candidate interior points as longitude and latitude in radians
+fn collect_mesh_points(polygon: &Polygon, config: &TilerConfig, candidate_points: &[Coord<f64>]) -> Result<Vec<(f64, f64, f64)>, GeoTilerError> {
+    let mut mesh_points_3d: Vec<(f64, f64, f64)> = get_boundary_points(polygon)?;
+
+    let rings: Vec<LineString> = containment_rings(polygon);
+    push_interior_points(polygon, &rings, config, candidate_points, &mut mesh_points_3d)?;
+
+    Ok(mesh_points_3d) 
+}
+
+/// Appends the candidate points which fall inside the polygon, converted to 3D Cartesian
+/// coordinates, to mesh points starting with the polygon's boundary points, then applies
+/// `config.max_vertices`.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude)
+/// * `rings` - The [`containment_rings`] of the polygon
+/// * `config` - The mesh generation settings
+/// * `candidate_points` - Candidate interior points as longitude and latitude in radians
+/// * `mesh_points_3d` - The boundary points of the polygon, to which interior points are appended
+fn push_interior_points(
+    polygon: &Polygon,
+    rings: &[LineString],
+    config: &TilerConfig,
+    candidate_points: &[Coord<f64>],
+    mesh_points_3d: &mut Vec<(f64, f64, f64)>
+) -> Result<(), GeoTilerError> {
+    for point in candidate_points {
+        // keep candidate points which are contained in the shape and outside of its holes
+        if is_interior_point(polygon, rings, config, point) {
+            let point_degrees: Coord<f64> = coord! {x: point.x.to_degrees(), y: point.y.to_degrees()};
+            let point_3d: (f64, f64, f64) = ll_to_cartesian(point_degrees.x, point_degrees.y)?;
+            mesh_points_3d.push(point_3d);
+        }
+    }
+
+    if let Some(max_vertices) = config.max_vertices {
+        let num_boundary_points: usize = boundary_ring_lengths(polygon).iter().sum();
+        decimate_interior_points(mesh_points_3d, num_boundary_points, max_vertices);
+    }
+
+    Ok(())
+}
+
+/// Returns true if a candidate point, as longitude and latitude in radians, lies inside the
+/// polygon and outside of its holes according to `config.containment_mode`.
+///
+/// `rings` must be the [`containment_rings`] of the polygon.
+fn is_interior_point(polygon: &Polygon, rings: &[LineString], config: &TilerConfig, point: &Coord<f64>) -> bool {
+    match config.containment_mode {
+        ContainmentMode::Spherical => polygon_contains(rings, point),
+        ContainmentMode::Planar => polygon.contains(&coord! {x: point.x.to_degrees(), y: point.y.to_degrees()}),
+    }
+}
+
+/// Counts the Fibonacci sphere points which would be used as interior points when meshing a
+/// polygon, without converting them to 3D or triangulating.
+///
+/// The points are selected with the same containment test as [`get_mesh_points`], so with the
+/// default of 3000 points the count is the number of mesh vertices that
+/// [`generate_polygon_feature_mesh`] adds to the boundary points. This makes it a cheap way to
+/// estimate mesh sizes before meshing many polygons.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude)
+/// * `fibonacci_points` - The number of points spread over the whole sphere
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number of sphere points inside the polygon and outside of its holes
+/// * `Err(GeoTilerError)` - A `FibonacciError` if `fibonacci_points` is 0, or an
+///   `InvalidPolygonError` if the polygon contains non-finite or out-of-range coordinates
+pub fn count_interior_points(polygon: &Polygon, fibonacci_points: usize) -> Result<usize, GeoTilerError> {
+    count_interior_points_with_config(polygon, &TilerConfig::default(), fibonacci_points)
+}
+
+/// Counts the interior points of a polygon like [`count_interior_points`], using the containment
+/// test selected by `config`.
+fn count_interior_points_with_config(polygon: &Polygon, config: &TilerConfig, fibonacci_points: usize) -> Result<usize, GeoTilerError> {
+    validate_polygon_coordinates(polygon)?;
+
+    let rings: Vec<LineString> = containment_rings(polygon);
+    let candidate_points: Vec<Coord<f64>> = fibonacci_sphere(fibonacci_points)?;
+
+    Ok(candidate_points.iter().filter(|point| is_interior_point(polygon, &rings, config, point)).count())
+}
+
+/// Removes interior points with a uniform stride so that there are at most `max_vertices`
+/// points in total. The leading `num_boundary_points` points are always kept.
+fn decimate_interior_points(mesh_points: &mut Vec<(f64, f64, f64)>, num_boundary_points: usize, max_vertices: usize) {
+    if mesh_points.len() <= max_vertices {
+        return;
+    }
+
+    let interior_points: Vec<(f64, f64, f64)> = mesh_points.split_off(num_boundary_points);
+    let interior_budget: usize = max_vertices.saturating_sub(num_boundary_points);
+
+    log::debug!(
+        "Decimating {} interior points down to {} to respect a limit of {} vertices",
+        interior_points.len(), interior_budget, max_vertices
+    );
+
+    // pick evenly spaced indices across the interior points
+    mesh_points.extend(
+        (0..interior_budget).map(|i| interior_points[i * interior_points.len() / interior_budget])
+    );
+}
+
+/// Converts the rings of a geographic polygon, as returned by [`boundary_rings`], to 3D Cartesian
+/// coordinates on a unit sphere.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude)
+///
+/// # Returns
+///
+/// * `Ok(Vec<(f64, f64, f64)>)` - The points of the outer ring followed by those of each hole in
+///   3D Cartesian coordinates, in ring order
+/// * `Err(GeoTilerError)` - An error if the ring is empty, has fewer than 3 points, or if any ring
+///   contains non-finite or out-of-range coordinates
+fn get_boundary_points(polygon: &Polygon) -> Result<Vec<(f64, f64, f64)>, GeoTilerError> {
     if polygon.exterior().is_empty() {
         return Err(GeoTilerError::EmptyPointSetError("Outer ring cannot be empty".to_string()));
     }
@@ -140,41 +874,1450 @@ pub fn get_mesh_points(polygon: &Polygon) -> Result<Vec<(f64, f64, f64)>, GeoTil
         return Err(GeoTilerError::MeshGenerationError("Outer ring must have at least 3 points to form a valid polygon".to_string()));
     }
 
-    // get fibonacci points
-    let fibonacci_points: Vec<Coord<f64>> = fibonacci_sphere(DEFAULT_FIBONACCI_POINT_COUNT)?;
-    let mut mesh_points_2d: Vec<Coord<f64>> = polygon.exterior().0.clone();
-    let outer_ring: [LineString; 1] = [polygon.exterior().clone()];
-    for point in fibonacci_points {
+    validate_polygon_coordinates(polygon)?;
 
-        // keep fibonacci points which are contained in the shape
-        if polygon_contains(&outer_ring, &point) {
-            mesh_points_2d.push(coord! {x: point.x.to_degrees(), y: point.y.to_degrees()});
+    let mut boundary_points: Vec<(f64, f64, f64)> = Vec::with_capacity(boundary_ring_lengths(polygon).iter().sum());
+    for ring in boundary_rings(polygon) {
+        for point in ring.coords() {
+            let point_3d: (f64, f64, f64) = ll_to_cartesian(point.x, point.y)?;
+            boundary_points.push(point_3d);
         }
     }
 
-    let mut mesh_points_3d: Vec<(f64, f64, f64)> = Vec::new();
-    for point in mesh_points_2d {
-        let point_3d: (f64, f64, f64) = ll_to_cartesian(point.x, point.y)?;
-        mesh_points_3d.push(point_3d);
+    Ok(boundary_points)
+}
+
+/// Rejects polygons covering more than a hemisphere, which the projection used for triangulation
+/// cannot handle, with a `MeshGenerationError` suggesting to split them.
+fn ensure_within_hemisphere(polygon: &Polygon) -> Result<(), GeoTilerError> {
+    if polygon_exceeds_hemisphere(polygon) {
+        return Err(GeoTilerError::MeshGenerationError(
+            "Polygon covers more than a hemisphere and cannot be projected for triangulation; split it into smaller polygons, for instance by clipping it to a grid".to_string()
+        ));
     }
 
-    Ok(mesh_points_3d) 
+    Ok(())
 }
 
-/// Wrapper for 2D coordinates that implements Vertex2d trait.
-/// Needed because we can't implement external traits on geo::Coord due to orphan rule.
-#[derive(Debug, Clone, Copy)]
-struct CoordVertex<T> {
-    x: T,
-    y: T
-}
+/// Returns the rings of a polygon which are meshed as constrained boundaries: the outer ring
+/// followed by every hole with at least 3 distinct points.
+///
+/// Consecutive duplicate coordinates, common in hand-edited data, are removed from every ring
+/// since the zero-length edges they form break the constrained triangulation. Holes are oriented
+/// opposite to the outer ring, so that the region between the outer ring and the holes lies on
+/// the same side of every boundary edge, as expected both by the constrained triangulation and by
+/// the spherical containment test.
+fn boundary_rings(polygon: &Polygon) -> Vec<LineString> {
+    let exterior_winding = polygon.exterior().winding_order();
 
-impl Vertex2d for CoordVertex<f64> {
-    fn x(self) -> f64 {
-        self.x
+    let mut rings: Vec<LineString> = vec![dedup_ring(polygon.exterior())];
+    for interior in polygon.interiors().iter().map(dedup_ring).filter(|interior| interior.0.len() >= 4) {
+        let mut hole: LineString = interior;
+        if exterior_winding.is_some() && hole.winding_order() == exterior_winding {
+            hole.0.reverse();
+        }
+
+        rings.push(hole);
     }
 
-    fn y(self) -> f64 {
-        self.y
+    rings
+}
+
+/// Returns the rings of a polygon prepared for the spherical containment test: the outer ring
+/// followed by every hole with at least 3 distinct points, converted to radians, with the outer
+/// ring clockwise and the holes counter-clockwise.
+///
+/// `polygon_contains` follows the d3 winding convention, in which the inside of a ring lies on
+/// its right. Orienting the rings explicitly makes the test independent of the winding of the
+/// input polygon, which would otherwise select the complement of the intended region on the
+/// sphere for one of the two orientations.
+fn containment_rings(polygon: &Polygon) -> Vec<LineString> {
+    let oriented: Polygon = polygon.orient(Direction::Reversed);
+
+    std::iter::once(oriented.exterior())
+        .chain(oriented.interiors().iter().filter(|interior| interior.0.len() >= 4))
+        .map(|ring| ring.coords().map(|c| coord! {x: c.x.to_radians(), y: c.y.to_radians()}).collect())
+        .collect()
+}
+
+/// Returns a copy of a ring without its consecutive duplicate coordinates, two coordinates being
+/// duplicates when both their longitudes and latitudes differ by at most [`DEDUP_EPSILON`].
+fn dedup_ring(ring: &LineString) -> LineString {
+    let mut coords: Vec<Coord<f64>> = Vec::with_capacity(ring.0.len());
+
+    for &coord in ring.coords() {
+        let is_duplicate: bool = coords.last().is_some_and(|last: &Coord<f64>| {
+            (last.x - coord.x).abs() <= DEDUP_EPSILON && (last.y - coord.y).abs() <= DEDUP_EPSILON
+        });
+
+        if !is_duplicate {
+            coords.push(coord);
+        }
     }
-}
\ No newline at end of file
+
+    LineString::new(coords)
+}
+
+/// Returns the number of points of each ring returned by [`boundary_rings`].
+fn boundary_ring_lengths(polygon: &Polygon) -> Vec<usize> {
+    boundary_rings(polygon).iter()
+        .map(|ring| ring.0.len())
+        .collect()
+}
+
+/// Projects a set of 3D mesh points to the 2D plane used for triangulation.
+///
+/// The points are rotated so that their mean lies on the south pole and then stereographically
+/// projected from the north pole. The mesh generators use the same projection, except that they
+/// center the rotation on the area-weighted centroid of the polygon's boundary, which is not
+/// biased toward densely sampled parts of the boundary, and they rotate the points to the pole set
+/// by [`TilerConfig::projection_pole`]. Use [`generate_polygon_feature_mesh_debug`] to get the
+/// exact 2D points fed to the triangulation.
+///
+/// A rotated point lying on or right next to the north pole cannot be projected. Such a point,
+/// which only occurs for polygons covering most of the sphere, is clamped with
+/// [`stereographic_projection_clamped`](crate::stereographic_projection_clamped) so that it stays
+/// slightly off the pole. It is then projected very far from the origin, on the outside of the
+/// triangulation. Only the projected coordinates are affected, the mesh vertices
+/// keep their original positions.
+///
+/// # Arguments
+///
+/// * `mesh_points` - 3D points on the unit sphere, such as those returned by [`get_mesh_points`]
+///
+/// # Returns
+///
+/// * `Ok(Vec<Coord<f64>>)` - The projected 2D points, in the same order as `mesh_points`
+/// * `Err(GeoTilerError)` - An error if the points cannot be rotated
+pub fn project_mesh_points(mesh_points: &[(f64, f64, f64)]) -> Result<Vec<Coord<f64>>, GeoTilerError> {
+    if mesh_points.is_empty() {
+        return Err(GeoTilerError::EmptyPointSetError("Cannot rotate an empty set of points".to_string()));
+    }
+
+    project_mesh_points_about(mesh_points, mean_point(mesh_points), Pole::South)
+}
+
+/// Chooses the point rotated to the projection pole before projecting mesh points which start with
+/// boundary rings of the given lengths, the outer ring first.
+///
+/// This is the area-weighted centroid of the outer ring, falling back to the mean of all the
+/// points when there is no boundary or it encloses no area.
+fn projection_center(mesh_points: &[(f64, f64, f64)], ring_lengths: &[usize]) -> Vector3<f64> {
+    let exterior_length: usize = ring_lengths.first().copied().unwrap_or(0);
+
+    spherical_polygon_centroid(&mesh_points[..exterior_length])
+        .unwrap_or_else(|| mean_point(mesh_points))
+}
+
+/// Rotates mesh points so that `center` lies on `pole` and projects them stereographically from
+/// the opposite pole, clamping points that end up on the projection pole.
+fn project_mesh_points_about(mesh_points: &[(f64, f64, f64)], center: Vector3<f64>, pole: Pole) -> Result<Vec<Coord<f64>>, GeoTilerError> {
+    let rotated_points: Vec<(f64, f64, f64)> = match pole {
+        // rotate points to south pole for better stereographic projection
+        Pole::South => rotate_points_to_south_pole_about(mesh_points, center)?,
+        // bringing the antipode of the center to the south pole brings the center to the north
+        // pole, and mirroring along z turns the projection from the south pole into one from the
+        // north pole
+        Pole::North => rotate_points_to_south_pole_about(mesh_points, -center)?
+            .into_iter()
+            .map(|(x, y, z)| (x, y, -z))
+            .collect(),
+    };
+
+    // do a stereographic projection
+    let mut projected_points: Vec<Coord<f64>> = Vec::with_capacity(rotated_points.len());
+    for point in rotated_points {
+        if point.2 >= 1.0 - PROJECTION_POLE_Z_GAP {
+            log::warn!("Mesh point {:?} lies on the projection pole after rotation and was clamped", point);
+        }
+
+        projected_points.push(stereographic_projection_clamped(point, PROJECTION_POLE_Z_GAP));
+    }
+
+    Ok(projected_points)
+}
+
+/// Generates a triangulated 3D mesh from a 2D geographic polygon and also returns the 2D
+/// projected points that were triangulated.
+///
+/// This runs exactly the same steps as [`generate_polygon_feature_mesh`], including the fast path
+/// for small triangles and quads and the jittered retries, and additionally returns the projected
+/// points, in the same order as the mesh vertices, so the triangulation input can be plotted to
+/// spot degeneracies such as nearly coincident points. When a retry succeeds, the returned points
+/// are those of the successful attempt. The vertices of a fan triangulated triangle or quad are
+/// only projected here, about the same center as a triangulated polygon.
+///
+/// # Arguments
+///
+/// * `polygon` - A geographic polygon with coordinates in decimal degrees (longitude, latitude).
+///   The polygon must have at least 3 boundary points and cannot be empty.
+///
+/// # Returns
+///
+/// * `Ok((PolygonMeshData, Vec<Coord<f64>>))` - The mesh and the projected 2D points, the `i`-th
+///   2D point corresponding to the `i`-th mesh vertex
+/// * `Err(GeoTilerError)` - Under the same conditions as [`generate_polygon_feature_mesh`]
+pub fn generate_polygon_feature_mesh_debug(polygon: &Polygon) -> Result<(PolygonMeshData, Vec<Coord<f64>>), GeoTilerError> {
+    let config: TilerConfig = TilerConfig::default();
+    let (mesh, projected_points) = generate_projected_mesh(polygon, &config)?;
+
+    let projected_points: Vec<Coord<f64>> = match projected_points {
+        Some(projected_points) => projected_points,
+        None => {
+            let center: Vector3<f64> = projection_center(&mesh.vertices, &[mesh.vertices.len()]);
+            project_mesh_points_about(&mesh.vertices, center, config.projection_pole)?
+        }
+    };
+
+    Ok((mesh, projected_points))
+}
+
+/// Generates a single mesh covering every fragment of a tile.
+///
+/// Each fragment is meshed with [`generate_polygon_feature_mesh`] and the meshes are merged in
+/// fragment order: the vertices are concatenated and the triangle indices of each fragment are
+/// offset by the number of vertices of the fragments before it. The fragments stay disconnected
+/// within the combined mesh, no vertices are shared between them.
+///
+/// # Arguments
+///
+/// * `tile` - The tile whose fragments are meshed
+///
+/// # Returns
+///
+/// * `Ok(PolygonMeshData)` - The combined mesh, which is empty if the tile has no fragments
+/// * `Err(GeoTilerError)` - The first error returned while meshing a fragment, or a
+///   `MeshGenerationError` if the combined mesh has more vertices than a `u32` index can address
+pub fn mesh_tile_combined(tile: &Tile) -> Result<PolygonMeshData, GeoTilerError> {
+    let mut combined: PolygonMeshData = PolygonMeshData {
+        vertices: Vec::new(),
+        triangles: Vec::new(),
+        boundary_indices: Vec::new(),
+    };
+
+    for polygon in &tile.polygons {
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(polygon)?;
+        append_mesh(&mut combined, mesh)?;
+    }
+
+    Ok(combined)
+}
+
+/// Appends the vertices of `mesh` to `combined`, offsetting its triangle and boundary indices
+/// by the number of vertices already in `combined`.
+///
+/// `combined` is left unchanged when an error is returned.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::MeshGenerationError` if an offset index does not fit in a `u32`.
+fn append_mesh(combined: &mut PolygonMeshData, mesh: PolygonMeshData) -> Result<(), GeoTilerError> {
+    let vertex_count: usize = combined.vertices.len() + mesh.vertices.len();
+    let index_range_error = || GeoTilerError::MeshGenerationError(
+        format!("Combined mesh has {} vertices, which exceeds the u32 triangle index range", vertex_count)
+    );
+
+    let offset: u32 = u32::try_from(combined.vertices.len()).map_err(|_| index_range_error())?;
+    let offset_indices = |indices: &[u32]| -> Result<Vec<u32>, GeoTilerError> {
+        indices.iter()
+            .map(|index| index.checked_add(offset).ok_or_else(index_range_error))
+            .collect()
+    };
+
+    let triangles: Vec<u32> = offset_indices(&mesh.triangles)?;
+    let boundary_indices: Vec<u32> = offset_indices(&mesh.boundary_indices)?;
+
+    combined.triangles.extend(triangles);
+    combined.boundary_indices.extend(boundary_indices);
+    combined.vertices.extend(mesh.vertices);
+
+    Ok(())
+}
+
+/// Generates a single indexed mesh covering every fragment of a grid.
+///
+/// Each tile is meshed with [`mesh_tile_combined`] and the meshes are concatenated in grid order.
+/// Vertices shared by several fragments, such as the points along a common tile boundary, are
+/// then welded into a single vertex, so the result can be rendered as one connected surface.
+/// Triangles are kept as they are, only their indices are remapped to the welded vertices.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles whose fragments are meshed
+///
+/// # Returns
+///
+/// * `Ok(PolygonMeshData)` - The merged mesh, which is empty if no tile has fragments
+/// * `Err(GeoTilerError)` - The first error returned while meshing a fragment, or a
+///   `MeshGenerationError` if the merged mesh has more vertices than a `u32` index can address
+pub fn merge_grid_mesh(grid: &[Tile]) -> Result<PolygonMeshData, GeoTilerError> {
+    let mut merged: PolygonMeshData = PolygonMeshData {
+        vertices: Vec::new(),
+        triangles: Vec::new(),
+        boundary_indices: Vec::new(),
+    };
+
+    for tile in grid {
+        let mesh: PolygonMeshData = mesh_tile_combined(tile)?;
+        append_mesh(&mut merged, mesh)?;
+    }
+
+    weld_vertices(&mut merged);
+
+    Ok(merged)
+}
+
+/// Removes duplicate and zero-area triangles from a mesh.
+///
+/// Welding vertices, as [`merge_grid_mesh`] does, can leave two triangles referencing the same
+/// three vertices, or collapse a thin triangle onto a line or a point. Two triangles are
+/// duplicates when they reference the same vertices, whatever their order or winding, and only
+/// the first one is kept. A triangle is zero-area when it repeats a vertex index or when its
+/// corners are collinear within [`COLLINEARITY_EPSILON`]. Vertices are left untouched, even if no
+/// triangle references them anymore.
+///
+/// # Arguments
+///
+/// * `mesh` - The mesh whose triangles are filtered in place
+///
+/// # Panics
+///
+/// Panics if a triangle references a vertex that does not exist, which [`PolygonMeshData::validate`]
+/// reports as an error.
+pub fn dedup_triangles(mesh: &mut PolygonMeshData) {
+    let mut seen: HashSet<[u32; 3]> = HashSet::new();
+    let mut triangles: Vec<u32> = Vec::with_capacity(mesh.triangles.len());
+
+    for (i, triangle) in mesh.triangle_iter().enumerate() {
+        let mut key: [u32; 3] = triangle;
+        key.sort_unstable();
+
+        let is_degenerate: bool = key[0] == key[1] || key[1] == key[2]
+            || 2.0 * mesh.triangle_area(i) <= COLLINEARITY_EPSILON;
+        if is_degenerate || !seen.insert(key) {
+            continue;
+        }
+
+        triangles.extend_from_slice(&triangle);
+    }
+
+    mesh.triangles = triangles;
+}
+
+/// Merges the vertices of a mesh which round to the same multiple of [`WELD_EPSILON`] on every
+/// axis, keeping the first occurrence and remapping the triangle indices accordingly.
+fn weld_vertices(mesh: &mut PolygonMeshData) {
+    let quantize = |value: f64| -> i64 { (value / WELD_EPSILON).round() as i64 };
+
+    let mut welded_indices: HashMap<(i64, i64, i64), u32> = HashMap::new();
+    let mut remapped: Vec<u32> = Vec::with_capacity(mesh.vertices.len());
+    let mut vertices: Vec<(f64, f64, f64)> = Vec::with_capacity(mesh.vertices.len());
+
+    for vertex in &mesh.vertices {
+        let key: (i64, i64, i64) = (quantize(vertex.0), quantize(vertex.1), quantize(vertex.2));
+        let index: u32 = *welded_indices.entry(key).or_insert_with(|| {
+            vertices.push(*vertex);
+            (vertices.len() - 1) as u32
+        });
+        remapped.push(index);
+    }
+
+    for index in mesh.triangles.iter_mut() {
+        *index = remapped[*index as usize];
+    }
+    for index in mesh.boundary_indices.iter_mut() {
+        *index = remapped[*index as usize];
+    }
+    mesh.boundary_indices.sort_unstable();
+    mesh.boundary_indices.dedup();
+    mesh.vertices = vertices;
+}
+
+/// Generates the meshes of every fragment in a grid and measures the time spent on each tile.
+///
+/// This is meant for profiling, to find the tiles whose fragments dominate the runtime. Each
+/// fragment is meshed with [`generate_polygon_feature_mesh`].
+///
+/// # Arguments
+///
+/// * `grid` - The tiles whose fragments are meshed
+///
+/// # Returns
+///
+/// * `Ok(Vec<(usize, Vec<PolygonMeshData>, Duration)>)` - One entry per tile with at least one
+///   fragment, in grid order, holding the tile's index in `grid`, the mesh of each of its
+///   fragments and the wall-clock time spent meshing them
+/// * `Err(GeoTilerError)` - The first error returned while meshing a fragment
+pub fn mesh_all_tiles_timed(grid: &[Tile]) -> Result<Vec<(usize, Vec<PolygonMeshData>, Duration)>, GeoTilerError> {
+    let mut timings: Vec<(usize, Vec<PolygonMeshData>, Duration)> = Vec::new();
+
+    for (index, tile) in grid.iter().enumerate() {
+        if tile.polygons.is_empty() {
+            continue;
+        }
+
+        let start: Instant = Instant::now();
+        let meshes: Vec<PolygonMeshData> = tile.polygons.iter()
+            .map(generate_polygon_feature_mesh)
+            .collect::<Result<_, _>>()?;
+        let elapsed: Duration = start.elapsed();
+
+        log::debug!("Meshed {} fragments of tile {} in {:?}", meshes.len(), index, elapsed);
+        timings.push((index, meshes, elapsed));
+    }
+
+    Ok(timings)
+}
+
+type TileMeshes = (usize, Vec<PolygonMeshData>);
+
+/// A mesh along with the 2D projected points it was triangulated from.
+type ProjectedMesh = (PolygonMeshData, Vec<Coord<f64>>);
+
+/// A mesh along with the 2D projected points it was triangulated from, if it was projected at all.
+type MaybeProjectedMesh = (PolygonMeshData, Option<Vec<Coord<f64>>>);
+
+/// Generates the meshes of every fragment in a grid, collecting failures instead of stopping at
+/// the first one.
+///
+/// Each fragment is meshed with [`generate_polygon_feature_mesh`]. A fragment that cannot be
+/// meshed is reported and skipped, so a single bad fragment does not abort a whole run.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles whose fragments are meshed
+///
+/// # Returns
+///
+/// A tuple holding:
+/// * `Vec<(usize, Vec<PolygonMeshData>)>` - One entry per tile with at least one successfully
+///   meshed fragment, in grid order, holding the tile's index in `grid` and the meshes of its
+///   fragments, in fragment order and without the failed ones
+/// * `Vec<(usize, GeoTilerError)>` - One entry per failed fragment, in grid order, holding the
+///   index in `grid` of the fragment's tile and the error returned while meshing it
+pub fn mesh_grid_collect_errors(grid: &[Tile]) -> (Vec<TileMeshes>, Vec<(usize, GeoTilerError)>) {
+    let mut meshes: Vec<TileMeshes> = Vec::new();
+    let mut errors: Vec<(usize, GeoTilerError)> = Vec::new();
+
+    for (index, tile) in grid.iter().enumerate() {
+        let mut tile_meshes: Vec<PolygonMeshData> = Vec::new();
+
+        for polygon in &tile.polygons {
+            match generate_polygon_feature_mesh(polygon) {
+                Ok(mesh) => tile_meshes.push(mesh),
+                Err(err) => {
+                    log::warn!("Failed to mesh a fragment of tile {}: {}", index, err);
+                    errors.push((index, err));
+                }
+            }
+        }
+
+        if !tile_meshes.is_empty() {
+            meshes.push((index, tile_meshes));
+        }
+    }
+
+    (meshes, errors)
+}
+
+/// Triangulates a closed ring of 3 or 4 distinct points on the unit sphere as a fan, without
+/// projecting it.
+///
+/// A quad is split along the diagonal which keeps both triangles wound like the ring, so that
+/// concave quads are handled, and every triangle is wound counter-clockwise as seen from outside
+/// the sphere.
+///
+/// # Returns
+///
+/// * `Some(PolygonMeshData)` - The mesh whose vertices are the ring's points, closing point
+///   included like in a triangulated mesh, but whose triangles only use the distinct points
+/// * `None` - If the ring does not have 3 or 4 distinct points
+fn fan_triangulation(ring: &[(f64, f64, f64)]) -> Option<PolygonMeshData> {
+    let vertices: Vec<(f64, f64, f64)> = ring.to_vec();
+    let distinct_count: usize = match vertices.len() {
+        length if length > 1 && vertices.first() == vertices.last() => length - 1,
+        length => length,
+    };
+
+    // positive when the triangle is counter-clockwise as seen from outside the sphere
+    let winding = |[a, b, c]: [u32; 3]| -> f64 {
+        let [a, b, c]: [Vector3<f64>; 3] = [a, b, c].map(|index| {
+            let (x, y, z) = vertices[index as usize];
+            Vector3::new(x, y, z)
+        });
+        (b - a).cross(&(c - a)).dot(&(a + b + c))
+    };
+
+    let mut triangles: Vec<[u32; 3]> = match distinct_count {
+        3 => vec![[0, 1, 2]],
+        4 if winding([0, 1, 2]) * winding([0, 2, 3]) >= 0.0 => vec![[0, 1, 2], [0, 2, 3]],
+        4 => vec![[1, 2, 3], [1, 3, 0]],
+        _ => return None,
+    };
+
+    if triangles.iter().map(|&triangle| winding(triangle)).sum::<f64>() < 0.0 {
+        for triangle in triangles.iter_mut() {
+            triangle.swap(1, 2);
+        }
+    }
+
+    Some(PolygonMeshData {
+        boundary_indices: (0..vertices.len() as u32).collect(),
+        vertices,
+        triangles: triangles.into_iter().flatten().collect(),
+    })
+}
+
+/// Triangulates the mesh points collected for a polygon according to `config.constrain_boundary`.
+///
+/// When the boundary is not constrained, the leading boundary points are dropped and only the
+/// interior points are triangulated.
+///
+/// # Arguments
+///
+/// * `mesh_points` - 3D points on the unit sphere, boundary points first and in ring order
+/// * `ring_lengths` - Number of points in each of the leading boundary rings, the outer ring first
+/// * `config` - The mesh generation settings
+///
+/// # Returns
+///
+/// * `Ok(ProjectedMesh)` - The triangulated mesh and the projected points it was built from
+/// * `Err(GeoTilerError)` - An error if there are too few interior points or triangulation fails
+fn triangulate_configured_points(
+    mut mesh_points: Vec<(f64, f64, f64)>,
+    ring_lengths: &[usize],
+    config: &TilerConfig
+) -> Result<ProjectedMesh, GeoTilerError> {
+    if config.constrain_boundary {
+        return triangulate_mesh_points(mesh_points, ring_lengths, config);
+    }
+
+    mesh_points.drain(..ring_lengths.iter().sum::<usize>());
+
+    if mesh_points.len() < 3 {
+        return Err(GeoTilerError::MeshGenerationError(
+            format!("Polygon contains {} interior points, at least 3 are needed to triangulate without the boundary", mesh_points.len())
+        ));
+    }
+
+    triangulate_mesh_points(mesh_points, &[], config)
+}
+
+/// Triangulates a set of 3D mesh points which start with the constrained boundary rings of the
+/// mesh, the outer ring first and then its holes.
+///
+/// The points are rotated about the area-weighted centroid of the outer ring, projected to 2D and
+/// triangulated with constrained Delaunay triangulation.
+///
+/// # Arguments
+///
+/// * `mesh_points` - 3D points on the unit sphere, boundary points first and in ring order
+/// * `ring_lengths` - Number of points in each of the leading boundary rings
+/// * `config` - The mesh generation settings
+///
+/// # Returns
+///
+/// * `Ok(ProjectedMesh)` - The input points as vertices along with the triangle indices, and the
+///   projected points in the same order
+/// * `Err(GeoTilerError)` - An error if rotation, projection or triangulation fails
+fn triangulate_mesh_points(
+    mesh_points: Vec<(f64, f64, f64)>,
+    ring_lengths: &[usize],
+    config: &TilerConfig
+) -> Result<ProjectedMesh, GeoTilerError> {
+    if mesh_points.is_empty() {
+        return Err(GeoTilerError::EmptyPointSetError("Cannot triangulate an empty set of points".to_string()));
+    }
+
+    let center: Vector3<f64> = projection_center(&mesh_points, ring_lengths);
+    let projected_points: Vec<Coord<f64>> = project_mesh_points_about(&mesh_points, center, config.projection_pole)?;
+
+    let mut mesh_data: PolygonMeshData = triangulate_projected_points(mesh_points, &projected_points, ring_lengths, config)?;
+    orient_triangles_outward(&mut mesh_data);
+
+    Ok((mesh_data, projected_points))
+}
+
+/// Rewinds every triangle of a mesh on the sphere so that it is counter-clockwise as seen from
+/// outside the sphere, whichever plane it was triangulated in.
+fn orient_triangles_outward(mesh: &mut PolygonMeshData) {
+    let vertices: &[(f64, f64, f64)] = &mesh.vertices;
+
+    for triangle in mesh.triangles.chunks_exact_mut(3) {
+        let [a, b, c]: [Vector3<f64>; 3] = [triangle[0], triangle[1], triangle[2]].map(|index| {
+            let (x, y, z) = vertices[index as usize];
+            Vector3::new(x, y, z)
+        });
+
+        if (b - a).cross(&(c - a)).dot(&(a + b + c)) < 0.0 {
+            triangle.swap(1, 2);
+        }
+    }
+}
+
+/// Triangulates 3D mesh points using their already projected 2D counterparts.
+///
+/// # Arguments
+///
+/// * `mesh_points` - 3D points on the unit sphere, boundary points first and in ring order
+/// * `projected_points` - The 2D projection of `mesh_points`, in the same order
+/// * `ring_lengths` - Number of points in each of the leading boundary rings, the outer ring first
+/// * `config` - The mesh generation settings
+///
+/// # Returns
+///
+/// * `Ok(PolygonMeshData)` - The input points as vertices along with the triangle indices
+/// * `Err(GeoTilerError)` - An error if triangulation fails
+fn triangulate_projected_points(
+    mesh_points: Vec<(f64, f64, f64)>,
+    projected_points: &[Coord<f64>],
+    ring_lengths: &[usize],
+    config: &TilerConfig
+) -> Result<PolygonMeshData, GeoTilerError> {
+    // calculate edges for the outer ring and each hole
+    let mut edges: Vec<Edge> = Vec::with_capacity(ring_lengths.iter().sum());
+    let mut ring_start: usize = 0;
+    for &ring_length in ring_lengths {
+        for i in (0..ring_length).rev() {
+            let edge: Edge = Edge {
+                from: (ring_start + i) as u32,
+                to: (ring_start + (i + ring_length - 1) % ring_length) as u32
+            };
+            edges.push(edge);
+        }
+
+        ring_start += ring_length;
+    }
+
+    let projected_points: Vec<CoordVertex<f64>> = projected_points.iter()
+        .map(|point| CoordVertex { x: point.x, y: point.y })
+        .collect();
+
+    // without boundary points there is nothing to constrain, so use a plain delaunay triangulation
+    let triangulation_result = if edges.is_empty() {
+        let triangulation_config: TriangulationConfiguration = TriangulationConfiguration {
+            bin_vertex_density_power: config.bin_vertex_density_power,
+        };
+
+        triangulation_from_2d_vertices(&projected_points, triangulation_config)
+    } else {
+        let triangulation_config: ConstrainedTriangulationConfiguration = ConstrainedTriangulationConfiguration {
+            bin_vertex_density_power: config.bin_vertex_density_power,
+        };
+
+        // generate mesh triangles using constrained delaunay triangulation
+        constrained_triangulation_from_2d_vertices(&projected_points, &edges, triangulation_config)
+    };
+
+    let delaunay_triangles: Triangulation = match triangulation_result {
+        Ok(triangles) => triangles,
+        Err(err) => return Err(GeoTilerError::TriangulationError(format!("Failed to generate triangulation: {}", err)))
+    };
+
+    let flattened_delaunay: Vec<u32> = delaunay_triangles.triangles.into_iter()
+        .flat_map(|triangle| triangle.into_iter())
+        .collect();
+    
+    Ok(PolygonMeshData {
+        vertices: mesh_points,
+        triangles: flattened_delaunay,
+        boundary_indices: (0..ring_lengths.iter().sum::<usize>() as u32).collect(),
+    })
+}
+
+/// Returns true if every exterior coordinate of the polygon lies within the range of
+/// longitude/latitude values expressed in radians.
+fn looks_like_radians(polygon: &Polygon) -> bool {
+    polygon.exterior()
+        .coords()
+        .all(|coord| coord.x.abs() <= PI && coord.y.abs() <= FRAC_PI_2)
+}
+
+/// Wrapper for 2D coordinates that implements Vertex2d trait.
+/// Needed because we can't implement external traits on geo::Coord due to orphan rule.
+#[derive(Debug, Clone, Copy)]
+struct CoordVertex<T> {
+    x: T,
+    y: T
+}
+
+impl Vertex2d for CoordVertex<f64> {
+    fn x(self) -> f64 {
+        self.x
+    }
+
+    fn y(self) -> f64 {
+        self.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::{polygon, MapCoords};
+
+    #[test]
+    fn test_boundary_only_mesh_uses_boundary_vertices() {
+        let polygon: Polygon = polygon![
+            (x: 10.0, y: 10.0),
+            (x: 12.0, y: 10.0),
+            (x: 13.0, y: 11.0),
+            (x: 12.0, y: 12.5),
+            (x: 10.0, y: 12.0),
+        ];
+
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh_boundary_only(&polygon).unwrap();
+
+        assert_eq!(mesh.vertices.len(), polygon.exterior().0.len());
+        assert!(mesh.triangle_count() > 0);
+        assert!(mesh.triangles.iter().all(|&index| (index as usize) < mesh.vertices.len()));
+    }
+
+    /// Returns a mesh of two triangles covering the square between the x, y and z axes' unit
+    /// points and their midpoint, with valid indices.
+    fn two_triangle_mesh() -> PolygonMeshData {
+        let diagonal: f64 = 1.0 / 3.0_f64.sqrt();
+
+        PolygonMeshData {
+            vertices: vec![(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (diagonal, diagonal, diagonal), (0.0, 0.0, 1.0)],
+            triangles: vec![0, 1, 2, 2, 3, 0],
+            boundary_indices: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_triangle_iter_yields_triplets() {
+        let mesh: PolygonMeshData = two_triangle_mesh();
+
+        assert_eq!(mesh.triangle_count(), 2);
+        assert_eq!(mesh.triangle_iter().collect::<Vec<[u32; 3]>>(), vec![[0, 1, 2], [2, 3, 0]]);
+    }
+
+    #[test]
+    fn test_spherical_centroid_of_symmetric_polygon() {
+        // symmetric about the equator and the 20° meridian
+        let polygon: Polygon = polygon![
+            (x: 10.0, y: -10.0), (x: 20.0, y: -12.0), (x: 30.0, y: -10.0), (x: 30.0, y: 10.0), (x: 20.0, y: 12.0), (x: 10.0, y: 10.0)
+        ];
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+
+        let (x, y, z) = mesh.spherical_centroid();
+        let centroid: Vector3<f64> = Vector3::new(x, y, z);
+        let (ex, ey, ez) = ll_to_cartesian(20.0, 0.0).unwrap();
+        let expected: Vector3<f64> = Vector3::new(ex, ey, ez);
+
+        assert!((centroid.magnitude() - 1.0).abs() < 1e-12);
+        // the interior points are a lattice, so they are only roughly symmetric
+        assert!(centroid.angle(&expected).to_degrees() < 1.0);
+
+        let empty: PolygonMeshData = PolygonMeshData { vertices: Vec::new(), triangles: Vec::new(), boundary_indices: Vec::new() };
+        assert_eq!(empty.spherical_centroid(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_bin_vertex_density_power_only_affects_binning() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 25.0, y: 10.0), (x: 15.0, y: 20.0), (x: 0.0, y: 15.0)];
+
+        let coarse: TilerConfig = TilerConfig { bin_vertex_density_power: 0.5, ..TilerConfig::default() };
+        let fine: TilerConfig = TilerConfig { bin_vertex_density_power: 2.0, ..TilerConfig::default() };
+        let coarse_mesh: PolygonMeshData = generate_polygon_feature_mesh_with_config(&polygon, &coarse).unwrap();
+        let fine_mesh: PolygonMeshData = generate_polygon_feature_mesh_with_config(&polygon, &fine).unwrap();
+
+        coarse_mesh.validate().unwrap();
+        fine_mesh.validate().unwrap();
+        assert_eq!(coarse_mesh.vertices, fine_mesh.vertices);
+    }
+
+    #[test]
+    fn test_strict_units_rejects_radian_coordinates() {
+        let degrees: Polygon = polygon![(x: 10.0, y: 40.0), (x: 15.0, y: 40.0), (x: 15.0, y: 45.0), (x: 10.0, y: 45.0)];
+        let radians: Polygon = degrees.map_coords(|c| coord! {x: c.x.to_radians(), y: c.y.to_radians()});
+        let strict: TilerConfig = TilerConfig { strict_units: true, ..TilerConfig::default() };
+
+        match generate_polygon_feature_mesh_with_config(&radians, &strict) {
+            Err(GeoTilerError::MeshGenerationError(message)) => assert!(message.contains("radians")),
+            other => panic!("expected a unit mismatch error, got {:?}", other),
+        }
+        assert!(generate_polygon_feature_mesh_with_config(&degrees, &strict).is_ok());
+        assert!(generate_polygon_feature_mesh(&radians).is_ok());
+    }
+
+
+    #[test]
+    fn test_containment_modes_agree_on_small_equatorial_polygon() {
+        let polygon: Polygon = polygon![(x: 10.0, y: -2.0), (x: 14.0, y: -2.0), (x: 14.0, y: 2.0), (x: 10.0, y: 2.0)];
+        let spherical: TilerConfig = TilerConfig::default();
+        let planar: TilerConfig = TilerConfig { containment_mode: ContainmentMode::Planar, ..TilerConfig::default() };
+
+        let spherical_points: Vec<(f64, f64, f64)> = get_mesh_points_with_config(&polygon, &spherical).unwrap();
+        let planar_points: Vec<(f64, f64, f64)> = get_mesh_points_with_config(&polygon, &planar).unwrap();
+
+        assert!(spherical_points.len() > boundary_ring_lengths(&polygon).iter().sum::<usize>());
+        assert_eq!(spherical_points, planar_points);
+    }
+
+
+    #[test]
+    fn test_debug_mesh_matches_regular_mesh() {
+        let large: Polygon = polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 20.0, y: 15.0), (x: 10.0, y: 25.0), (x: 0.0, y: 15.0)];
+        let quad: Polygon = polygon![(x: 0.0, y: 0.0), (x: 0.5, y: 0.0), (x: 0.5, y: 0.5), (x: 0.0, y: 0.5)];
+
+        for polygon in [large, quad] {
+            let (debug_mesh, projected_points) = generate_polygon_feature_mesh_debug(&polygon).unwrap();
+            assert_eq!(projected_points.len(), debug_mesh.vertices.len());
+            assert!(projected_points.iter().all(|point| point.x.is_finite() && point.y.is_finite()));
+            assert_eq!(debug_mesh, generate_polygon_feature_mesh(&polygon).unwrap());
+        }
+    }
+
+
+    #[test]
+    fn test_triangulation_is_retried_until_it_succeeds() {
+        let fail_twice = |attempt: usize| -> Result<usize, GeoTilerError> {
+            if attempt < 2 {
+                Err(GeoTilerError::TriangulationError("degenerate projection".to_string()))
+            } else {
+                Ok(attempt)
+            }
+        };
+        assert_eq!(triangulate_with_retries(3, fail_twice).unwrap(), 2);
+        assert!(matches!(triangulate_with_retries(2, fail_twice), Err(GeoTilerError::TriangulationError(_))));
+        assert!(matches!(triangulate_with_retries(0, fail_twice), Err(GeoTilerError::TriangulationError(_))));
+
+        // other errors are not retried
+        let mut calls: usize = 0;
+        let result: Result<(), GeoTilerError> = triangulate_with_retries(3, |_| {
+            calls += 1;
+            Err(GeoTilerError::MeshGenerationError("not a triangulation failure".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+
+        // the retries perturb the interior points deterministically
+        let lattice: Vec<Coord<f64>> = fibonacci_sphere(DEFAULT_FIBONACCI_POINT_COUNT).unwrap();
+        let jittered: Vec<Coord<f64>> = fibonacci_sphere_jittered(DEFAULT_FIBONACCI_POINT_COUNT, 1, TRIANGULATION_RETRY_JITTER).unwrap();
+        assert_ne!(lattice, jittered);
+        assert_eq!(jittered, fibonacci_sphere_jittered(DEFAULT_FIBONACCI_POINT_COUNT, 1, TRIANGULATION_RETRY_JITTER).unwrap());
+    }
+
+
+    #[test]
+    fn test_unconstrained_mesh_drops_boundary_vertices() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 40.0, y: 0.0), (x: 40.0, y: 30.0), (x: 0.0, y: 30.0)];
+        let unconstrained_config: TilerConfig = TilerConfig { constrain_boundary: false, ..TilerConfig::default() };
+
+        let constrained: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+        let unconstrained: PolygonMeshData = generate_polygon_feature_mesh_with_config(&polygon, &unconstrained_config).unwrap();
+
+        let boundary_count: usize = boundary_ring_lengths(&polygon).iter().sum();
+        assert_eq!(constrained.boundary_indices.len(), boundary_count);
+        assert_eq!(unconstrained.vertices.len(), constrained.vertices.len() - boundary_count);
+        assert_eq!(unconstrained.vertices[..], constrained.vertices[boundary_count..]);
+        assert!(unconstrained.boundary_indices.is_empty());
+        assert!(unconstrained.triangles.iter().all(|&index| (index as usize) < unconstrained.vertices.len()));
+
+        // a polygon too small to hold interior points cannot be meshed without its boundary
+        let tiny: Polygon = polygon![(x: 0.0, y: 0.0), (x: 0.1, y: 0.0), (x: 0.1, y: 0.1), (x: 0.0, y: 0.1)];
+        assert!(matches!(
+            generate_polygon_feature_mesh_with_config(&tiny, &unconstrained_config),
+            Err(GeoTilerError::MeshGenerationError(_))
+        ));
+    }
+
+
+    #[test]
+    fn test_polygon_touching_north_pole_is_meshed() {
+        // enough vertices to go through the interior points and the projection rather than a fan
+        let polygon: Polygon = polygon![(x: -40.0, y: 60.0), (x: 0.0, y: 55.0), (x: 40.0, y: 60.0), (x: 30.0, y: 75.0), (x: 0.0, y: 90.0), (x: -30.0, y: 75.0)];
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+        assert!(mesh.vertices.len() > mesh.boundary_vertex_indices().len());
+
+        assert!(mesh.vertices.iter().any(|&(x, y, z)| x.abs() < 1e-9 && y.abs() < 1e-9 && (z - 1.0).abs() < 1e-9));
+        assert!(mesh.vertices.iter().all(|&(x, y, z)| ((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-9));
+        assert!(!mesh.triangles.is_empty());
+        assert!(mesh.triangles.iter().all(|&index| (index as usize) < mesh.vertices.len()));
+
+        let (_, projected_points) = generate_polygon_feature_mesh_debug(&polygon).unwrap();
+        assert!(projected_points.iter().all(|point| point.x.is_finite() && point.y.is_finite()));
+    }
+
+
+    #[test]
+    fn test_total_area_converges_to_spherical_area() {
+        // the octant x, y, z >= 0 covers π/2 steradians
+        let coarse: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)],
+            triangles: vec![0, 1, 2],
+            boundary_indices: Vec::new(),
+        };
+        assert!((coarse.triangle_area(0) - 3.0_f64.sqrt() / 2.0).abs() < 1e-12);
+
+        // split the octant into four triangles through the normalized edge midpoints
+        let m: f64 = 1.0 / 2.0_f64.sqrt();
+        let fine: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0), (m, m, 0.0), (0.0, m, m), (m, 0.0, m)],
+            triangles: vec![0, 3, 5, 3, 1, 4, 5, 4, 2, 3, 4, 5],
+            boundary_indices: Vec::new(),
+        };
+
+        let coarse_area: f64 = coarse.total_area();
+        let fine_area: f64 = fine.total_area();
+        assert!(coarse_area > 0.0);
+        assert!(coarse_area < fine_area);
+        assert!(fine_area < std::f64::consts::FRAC_PI_2);
+        assert!((fine_area - std::f64::consts::FRAC_PI_2).abs() < (coarse_area - std::f64::consts::FRAC_PI_2).abs());
+    }
+
+
+    #[test]
+    fn test_bounding_sphere_of_known_mesh() {
+        let mesh: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(1.0, 0.0, 0.0), (-1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, -1.0, 0.0)],
+            triangles: vec![0, 2, 1, 1, 3, 0],
+            boundary_indices: Vec::new(),
+        };
+        let (center, radius) = mesh.bounding_sphere();
+        assert_eq!(center, (0.0, 0.0, 0.0));
+        assert_eq!(radius, 1.0);
+
+        let (center, radius) = two_triangle_mesh().bounding_sphere();
+        let d: f64 = 1.0 / 3.0_f64.sqrt();
+        let expected: Vector3<f64> = Vector3::new(1.0 + d, 1.0 + d, 1.0 + d) / 4.0;
+        assert!((Vector3::new(center.0, center.1, center.2) - expected).magnitude() < 1e-12);
+        assert!((radius - (Vector3::new(1.0, 0.0, 0.0) - expected).magnitude()).abs() < 1e-12);
+
+        let empty: PolygonMeshData = PolygonMeshData { vertices: Vec::new(), triangles: Vec::new(), boundary_indices: Vec::new() };
+        assert_eq!(empty.bounding_sphere(), ((0.0, 0.0, 0.0), 0.0));
+    }
+
+
+    #[test]
+    fn test_max_vertices_keeps_boundary_and_respects_cap() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 40.0, y: 0.0), (x: 40.0, y: 30.0), (x: 0.0, y: 30.0)];
+        let unlimited: Vec<(f64, f64, f64)> = get_mesh_points(&polygon).unwrap();
+        let boundary_count: usize = boundary_ring_lengths(&polygon).iter().sum();
+        let cap: usize = boundary_count + (unlimited.len() - boundary_count) / 3;
+
+        let config: TilerConfig = TilerConfig { max_vertices: Some(cap), ..TilerConfig::default() };
+        let capped: Vec<(f64, f64, f64)> = get_mesh_points_with_config(&polygon, &config).unwrap();
+
+        assert_eq!(capped.len(), cap);
+        assert_eq!(capped[..boundary_count], unlimited[..boundary_count]);
+        assert!(capped[boundary_count..].iter().all(|point| unlimited[boundary_count..].contains(point)));
+
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh_with_config(&polygon, &config).unwrap();
+        assert!(mesh.vertices.len() <= cap);
+        assert_eq!(mesh.boundary_indices.len(), boundary_count);
+
+        // a limit below the boundary size keeps every boundary vertex and drops all interior points
+        let tight: TilerConfig = TilerConfig { max_vertices: Some(2), ..TilerConfig::default() };
+        assert_eq!(get_mesh_points_with_config(&polygon, &tight).unwrap(), unlimited[..boundary_count]);
+    }
+
+
+    #[test]
+    fn test_lonlat_triangles_stay_within_polygon_bbox() {
+        let polygon: Polygon = polygon![(x: -20.0, y: -10.0), (x: 20.0, y: -10.0), (x: 20.0, y: 10.0), (x: 0.0, y: 10.0), (x: -20.0, y: 10.0)];
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+        let triangles: Vec<[(f64, f64); 3]> = mesh.to_lonlat_triangles().unwrap();
+
+        // the great-circle edges at ±10° bulge slightly poleward, by about 0.15° at the middle
+        assert_eq!(triangles.len(), mesh.triangle_count());
+        for (lon, lat) in triangles.iter().flatten() {
+            assert!((-20.0 - 1e-9..=20.0 + 1e-9).contains(lon));
+            assert!((-10.5..=10.5).contains(lat));
+        }
+
+        let broken: PolygonMeshData = PolygonMeshData { triangles: vec![0, 1, 99], ..two_triangle_mesh() };
+        assert!(matches!(broken.to_lonlat_triangles(), Err(GeoTilerError::MeshGenerationError(_))));
+    }
+
+
+    #[test]
+    fn test_mesh_all_tiles_timed_reports_each_non_empty_tile() {
+        let mut grid: Vec<Tile> = crate::generate_grid(20).unwrap();
+        let polygon: Polygon = polygon![(x: -15.0, y: -5.0), (x: 15.0, y: -5.0), (x: 15.0, y: 5.0), (x: -15.0, y: 5.0)];
+        crate::clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        let non_empty: Vec<usize> = (0..grid.len()).filter(|&index| !grid[index].polygons.is_empty()).collect();
+        let timings: Vec<(usize, Vec<PolygonMeshData>, Duration)> = mesh_all_tiles_timed(&grid).unwrap();
+
+        assert!(non_empty.len() > 1);
+        assert_eq!(timings.iter().map(|(index, _, _)| *index).collect::<Vec<usize>>(), non_empty);
+        for (index, meshes, duration) in &timings {
+            assert_eq!(meshes.len(), grid[*index].polygons.len());
+            assert!(*duration > Duration::ZERO);
+        }
+    }
+
+
+    #[test]
+    fn test_mesh_tile_combined_offsets_fragment_indices() {
+        let mut tile: Tile = Tile::new(polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 20.0, y: 20.0), (x: 0.0, y: 20.0)]);
+        tile.polygons.push(polygon![(x: 1.0, y: 1.0), (x: 9.0, y: 1.0), (x: 9.0, y: 9.0), (x: 1.0, y: 9.0)]);
+        tile.polygons.push(polygon![(x: 11.0, y: 11.0), (x: 19.0, y: 11.0), (x: 15.0, y: 19.0)]);
+
+        let first: PolygonMeshData = generate_polygon_feature_mesh(&tile.polygons[0]).unwrap();
+        let second: PolygonMeshData = generate_polygon_feature_mesh(&tile.polygons[1]).unwrap();
+        let combined: PolygonMeshData = mesh_tile_combined(&tile).unwrap();
+
+        let offset: usize = first.vertices.len();
+        assert_eq!(combined.vertices.len(), offset + second.vertices.len());
+        assert_eq!(combined.vertices[..offset], first.vertices[..]);
+        assert_eq!(combined.vertices[offset..], second.vertices[..]);
+
+        let (first_triangles, second_triangles) = combined.triangles.split_at(first.triangles.len());
+        assert_eq!(first_triangles, &first.triangles[..]);
+        assert!(second_triangles.iter().all(|&index| index as usize >= offset && (index as usize) < combined.vertices.len()));
+        assert_eq!(second_triangles.iter().map(|&index| index - offset as u32).collect::<Vec<u32>>(), second.triangles);
+    }
+
+    #[test]
+    fn test_append_mesh_rejects_index_overflow() {
+        let mut combined: PolygonMeshData = two_triangle_mesh();
+        let overflowing: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(1.0, 0.0, 0.0)],
+            triangles: vec![0, 0, u32::MAX - 1],
+            boundary_indices: Vec::new(),
+        };
+
+        assert!(matches!(append_mesh(&mut combined, overflowing), Err(GeoTilerError::MeshGenerationError(_))));
+        assert_eq!(combined, two_triangle_mesh());
+    }
+
+
+    #[test]
+    fn test_merge_grid_mesh_keeps_every_triangle_and_welds_shared_vertices() {
+        let mut grid: Vec<Tile> = crate::generate_grid(20).unwrap();
+        let polygon: Polygon = polygon![(x: -15.0, y: -5.0), (x: 15.0, y: -5.0), (x: 15.0, y: 5.0), (x: -15.0, y: 5.0)];
+        crate::clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        let fragment_meshes: Vec<PolygonMeshData> = grid.iter()
+            .flat_map(|tile| &tile.polygons)
+            .map(|fragment| generate_polygon_feature_mesh(fragment).unwrap())
+            .collect();
+        assert!(fragment_meshes.len() > 1);
+
+        let merged: PolygonMeshData = merge_grid_mesh(&grid).unwrap();
+        let triangle_count: usize = fragment_meshes.iter().map(|mesh| mesh.triangle_count()).sum();
+        let vertex_count: usize = fragment_meshes.iter().map(|mesh| mesh.vertices.len()).sum();
+
+        assert_eq!(merged.triangle_count(), triangle_count);
+        assert!(merged.vertices.len() < vertex_count);
+        assert!(merged.triangles.iter().all(|&index| (index as usize) < merged.vertices.len()));
+
+        // welding only merges coincident vertices, so every triangle keeps its corners
+        let corners = |mesh: &PolygonMeshData| -> Vec<(f64, f64, f64)> {
+            mesh.triangles.iter().map(|&index| mesh.vertices[index as usize]).collect()
+        };
+        let expected: Vec<(f64, f64, f64)> = fragment_meshes.iter().flat_map(corners).collect();
+        for (corner, expected) in corners(&merged).iter().zip(&expected) {
+            assert!((corner.0 - expected.0).abs() < 1e-8 && (corner.1 - expected.1).abs() < 1e-8 && (corner.2 - expected.2).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_mesh_with_points_uses_the_given_interior_points() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+        let mut interior: Vec<(f64, f64)> = Vec::new();
+        for longitude in [2.0, 4.0, 6.0, 8.0] {
+            for latitude in [2.0, 4.0, 6.0, 8.0] {
+                interior.push((longitude, latitude));
+            }
+        }
+        let outside: (f64, f64) = (30.0, 30.0);
+
+        let mut candidates: Vec<(f64, f64)> = interior.clone();
+        candidates.push(outside);
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh_with_points(&polygon, &candidates).unwrap();
+
+        let boundary_count: usize = get_boundary_points(&polygon).unwrap().len();
+        assert_eq!(mesh.vertices.len(), boundary_count + interior.len());
+
+        let is_vertex = |(longitude, latitude): (f64, f64)| -> bool {
+            let point: (f64, f64, f64) = ll_to_cartesian(longitude, latitude).unwrap();
+            mesh.vertices.iter().any(|v| (v.0 - point.0).abs() < 1e-12 && (v.1 - point.1).abs() < 1e-12 && (v.2 - point.2).abs() < 1e-12)
+        };
+        assert!(interior.iter().all(|&point| is_vertex(point)));
+        assert!(!is_vertex(outside));
+
+        assert!(matches!(
+            generate_polygon_feature_mesh_with_points(&polygon, &[(5.0, 5.0), (5.0, 95.0)]),
+            Err(GeoTilerError::CoordinateRangeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_triangle_accessor_checks_indices() {
+        let mut mesh: PolygonMeshData = two_triangle_mesh();
+        let diagonal: f64 = 1.0 / 3.0_f64.sqrt();
+
+        assert_eq!(mesh.triangle(1), Some([(diagonal, diagonal, diagonal), (0.0, 0.0, 1.0), (1.0, 0.0, 0.0)]));
+        assert_eq!(mesh.triangle(2), None);
+        assert_eq!(mesh.triangle(usize::MAX), None);
+
+        // a dangling vertex index is reported instead of panicking
+        mesh.triangles[4] = 9;
+        assert!(mesh.triangle(0).is_some());
+        assert_eq!(mesh.triangle(1), None);
+    }
+
+    #[test]
+    fn test_count_interior_points_matches_mesh_vertices() {
+        let polygon: Polygon = polygon![
+            (x: 0.0, y: 0.0), (x: 20.0, y: -5.0), (x: 35.0, y: 5.0),
+            (x: 30.0, y: 25.0), (x: 15.0, y: 30.0), (x: -5.0, y: 20.0)
+        ];
+
+        let count: usize = count_interior_points(&polygon, 3000).unwrap();
+        assert!(count > 0);
+
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+        let boundary_count: usize = get_boundary_points(&polygon).unwrap().len();
+        assert_eq!(count, mesh.vertices.len() - boundary_count);
+
+        // more sphere points give more interior points
+        assert!(count_interior_points(&polygon, 12000).unwrap() > count);
+        assert!(matches!(count_interior_points(&polygon, 0), Err(GeoTilerError::FibonacciError(_))));
+    }
+
+    #[test]
+    fn test_clockwise_polygon_is_filled_inside_its_boundary() {
+        let counter_clockwise: Polygon = polygon![(x: 10.0, y: 10.0), (x: 40.0, y: 10.0), (x: 40.0, y: 30.0), (x: 25.0, y: 40.0), (x: 10.0, y: 30.0)];
+        let mut clockwise: Polygon = counter_clockwise.clone();
+        clockwise.exterior_mut(|exterior| exterior.0.reverse());
+        assert!(clockwise.exterior().is_cw());
+
+        let rings: Vec<LineString> = containment_rings(&clockwise);
+        assert!(rings[0].is_cw());
+
+        let count: usize = count_interior_points(&clockwise, 3000).unwrap();
+        assert!(count > 0 && count < 1500);
+        assert_eq!(count, count_interior_points(&counter_clockwise, 3000).unwrap());
+
+        // every interior point lies inside the drawn region, allowing for the great-circle bulge of the edges
+        let points: Vec<(f64, f64, f64)> = get_mesh_points(&clockwise).unwrap();
+        let boundary_count: usize = get_boundary_points(&clockwise).unwrap().len();
+        for &point in &points[boundary_count..] {
+            let (longitude, latitude): (f64, f64) = cartesian_to_ll(point).unwrap();
+            assert!((10.0..=40.0).contains(&longitude));
+            assert!((9.0..=41.0).contains(&latitude));
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_a_generated_mesh() {
+        assert!(two_triangle_mesh().validate().is_ok());
+
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 14.0, y: 5.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+        assert!(mesh.vertices.len() > get_boundary_points(&polygon).unwrap().len());
+        assert!(mesh.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_degenerate_meshes() {
+        let message = |mesh: PolygonMeshData| -> String {
+            match mesh.validate() {
+                Err(GeoTilerError::TriangulationError(message)) => message,
+                other => panic!("expected a TriangulationError, got {:?}", other),
+            }
+        };
+
+        let empty: PolygonMeshData = PolygonMeshData { vertices: Vec::new(), triangles: Vec::new(), boundary_indices: Vec::new() };
+        assert_eq!(message(empty), "Mesh has no vertices");
+
+        let mut partial: PolygonMeshData = two_triangle_mesh();
+        partial.triangles.pop();
+        assert_eq!(message(partial), "Mesh has 5 triangle indices, which is not a multiple of 3");
+
+        let mut dangling: PolygonMeshData = two_triangle_mesh();
+        dangling.triangles[1] = 4;
+        assert_eq!(message(dangling), "Triangle references vertex 4 but the mesh has 4 vertices");
+
+        let collinear: PolygonMeshData = PolygonMeshData {
+            vertices: vec![(0.0, 0.0, 1.0), (0.5, 0.0, 1.0), (1.0, 0.0, 1.0)],
+            triangles: vec![0, 1, 2],
+            boundary_indices: Vec::new(),
+        };
+        assert_eq!(message(collinear), "All 3 mesh vertices are collinear");
+    }
+
+    #[test]
+    fn test_projection_pole_settings_give_triangles_of_the_same_quality() {
+        // smallest interior angle of any triangle in the mesh, in degrees
+        let min_angle = |mesh: &PolygonMeshData| -> f64 {
+            (0..mesh.triangle_count())
+                .flat_map(|i| {
+                    let corners: Vec<Vector3<f64>> = mesh.triangle(i).unwrap().iter().map(|&(x, y, z)| Vector3::new(x, y, z)).collect();
+                    (0..3).map(move |k| {
+                        let (a, b, c) = (corners[k], corners[(k + 1) % 3], corners[(k + 2) % 3]);
+                        (b - a).angle(&(c - a)).to_degrees()
+                    })
+                })
+                .fold(f64::MAX, f64::min)
+        };
+
+        let polygon: Polygon = polygon![(x: 20.0, y: -60.0), (x: 50.0, y: -62.0), (x: 55.0, y: -40.0), (x: 35.0, y: -30.0), (x: 15.0, y: -42.0)];
+        let south: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+        let north_config: TilerConfig = TilerConfig { projection_pole: Pole::North, ..TilerConfig::default() };
+        let north: PolygonMeshData = generate_polygon_feature_mesh_with_config(&polygon, &north_config).unwrap();
+
+        assert_eq!(TilerConfig::default().projection_pole, Pole::South);
+        assert_eq!(north.vertices, south.vertices);
+        assert_eq!(north.triangle_count(), south.triangle_count());
+        assert!((north.total_area() - south.total_area()).abs() < 1e-9);
+        assert!((min_angle(&north) - min_angle(&south)).abs() < 1e-6);
+
+        // both settings center the projection on the polygon, so each point lies as far from the origin
+        let south_projected: Vec<Coord<f64>> = generate_projected_mesh(&polygon, &TilerConfig::default()).unwrap().1.unwrap();
+        let north_projected: Vec<Coord<f64>> = generate_projected_mesh(&polygon, &north_config).unwrap().1.unwrap();
+        assert_ne!(north_projected, south_projected);
+        for (north_point, south_point) in north_projected.iter().zip(&south_projected) {
+            assert!((north_point.x.hypot(north_point.y) - south_point.x.hypot(south_point.y)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_mesh_grid_collect_errors_reports_bad_fragments() {
+        let mut grid: Vec<Tile> = crate::generate_grid(20).unwrap();
+        crate::clip_polygon_to_tiles(&mut grid, &polygon![(x: 5.0, y: -5.0), (x: 15.0, y: -5.0), (x: 15.0, y: 5.0), (x: 5.0, y: 5.0)]).unwrap();
+        crate::clip_polygon_to_tiles(&mut grid, &polygon![(x: 25.0, y: -5.0), (x: 35.0, y: -5.0), (x: 35.0, y: 5.0), (x: 25.0, y: 5.0)]).unwrap();
+        let good_tiles: Vec<usize> = (0..grid.len()).filter(|&i| !grid[i].polygons.is_empty()).collect();
+        assert_eq!(good_tiles.len(), 2);
+
+        // a collinear fragment cannot be meshed
+        grid[good_tiles[0]].polygons.push(polygon![(x: 1.0, y: 1.0), (x: 2.0, y: 2.0), (x: 3.0, y: 3.0)]);
+
+        let (meshes, errors) = mesh_grid_collect_errors(&grid);
+
+        assert_eq!(meshes.iter().map(|(index, _)| *index).collect::<Vec<usize>>(), good_tiles);
+        assert!(meshes.iter().all(|(_, tile_meshes)| tile_meshes.len() == 1));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, good_tiles[0]);
+        assert!(matches!(errors[0].1, GeoTilerError::InvalidPolygonError(_)));
+    }
+
+    #[test]
+    fn test_reproject_to_longitude_latitude() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 5.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+
+        let lonlat: Vec<(f64, f64)> = mesh.reproject(cartesian_to_ll).unwrap();
+        assert_eq!(lonlat.len(), mesh.vertices.len());
+        for (longitude, latitude) in &lonlat {
+            assert!((-1e-9..=10.0 + 1e-9).contains(longitude));
+            assert!((-1e-9..=10.5).contains(latitude));
+        }
+
+        let failing: Result<Vec<(f64, f64)>, GeoTilerError> = mesh.reproject(|_| Err(GeoTilerError::ProjectionError("unsupported".to_string())));
+        assert!(matches!(failing, Err(GeoTilerError::ProjectionError(_))));
+    }
+
+    #[test]
+    fn test_lod_meshes_share_boundary_vertices() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 30.0, y: 0.0), (x: 30.0, y: 30.0), (x: 0.0, y: 30.0)];
+
+        // requested fine-to-coarse, returned coarse-to-fine
+        let meshes: Vec<PolygonMeshData> = generate_lod_meshes(&polygon, &[1000, 100]).unwrap();
+        assert_eq!(meshes.len(), 2);
+        let (coarse, fine) = (&meshes[0], &meshes[1]);
+        assert!(fine.vertices.len() > coarse.vertices.len());
+
+        let boundary_count: usize = get_boundary_points(&polygon).unwrap().len();
+        assert_eq!(coarse.boundary_vertex_indices().len(), boundary_count);
+        assert_eq!(fine.boundary_vertex_indices().len(), boundary_count);
+        assert_eq!(coarse.vertices[..boundary_count], fine.vertices[..boundary_count]);
+
+        assert_eq!(fine.vertices.len() - boundary_count, count_interior_points(&polygon, 1000).unwrap());
+        assert!(matches!(generate_lod_meshes(&polygon, &[100, 0]), Err(GeoTilerError::FibonacciError(_))));
+    }
+
+    #[test]
+    fn test_duplicate_boundary_coordinates_are_meshed_once() {
+        let clean: Polygon = polygon![
+            (x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 15.0, y: 8.0), (x: 10.0, y: 16.0), (x: 0.0, y: 16.0), (x: -5.0, y: 8.0)
+        ];
+        let duplicated: Polygon = polygon![
+            (x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 0.0), (x: 15.0, y: 8.0), (x: 15.0 + 1e-12, y: 8.0),
+            (x: 10.0, y: 16.0), (x: 0.0, y: 16.0), (x: -5.0, y: 8.0)
+        ];
+
+        let clean_mesh: PolygonMeshData = generate_polygon_feature_mesh(&clean).unwrap();
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&duplicated).unwrap();
+
+        assert_eq!(mesh.boundary_vertex_indices().len(), clean_mesh.boundary_vertex_indices().len());
+        assert_eq!(mesh.vertices.len(), clean_mesh.vertices.len());
+        assert_eq!(mesh.triangle_count(), clean_mesh.triangle_count());
+        assert!(mesh.validate().is_ok());
+    }
+
+    #[test]
+    fn test_gpu_buffers_have_a_flat_layout() {
+        let mesh: PolygonMeshData = two_triangle_mesh();
+
+        let positions: Vec<f32> = mesh.positions_f32();
+        assert_eq!(positions.len(), 3 * mesh.vertices.len());
+        for (chunk, &(x, y, z)) in positions.chunks(3).zip(&mesh.vertices) {
+            assert!((chunk[0] as f64 - x).abs() < 1e-7);
+            assert!((chunk[1] as f64 - y).abs() < 1e-7);
+            assert!((chunk[2] as f64 - z).abs() < 1e-7);
+        }
+
+        assert_eq!(mesh.indices_u32(), &[0, 1, 2, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_only_quads_without_interior_points_are_fan_triangulated() {
+        // large enough to contain many Fibonacci points, so it is filled like any other polygon
+        let large: Polygon = polygon![(x: 0.0, y: 0.0), (x: 40.0, y: 0.0), (x: 40.0, y: 30.0), (x: 0.0, y: 30.0)];
+        let interior_count: usize = count_interior_points(&large, DEFAULT_FIBONACCI_POINT_COUNT).unwrap();
+        assert!(interior_count > 0);
+        let large_mesh: PolygonMeshData = generate_polygon_feature_mesh(&large).unwrap();
+        assert_eq!(large_mesh.vertices.len(), get_boundary_points(&large).unwrap().len() + interior_count);
+        assert!(large_mesh.triangle_count() > interior_count);
+
+        let quad: Polygon = polygon![(x: 100.0, y: 5.0), (x: 101.0, y: 5.0), (x: 101.0, y: 6.0), (x: 100.0, y: 6.0)];
+        assert_eq!(count_interior_points(&quad, DEFAULT_FIBONACCI_POINT_COUNT).unwrap(), 0);
+
+        let (mesh, projected_points) = generate_polygon_feature_mesh_debug(&quad).unwrap();
+        let boundary: Vec<(f64, f64, f64)> = get_boundary_points(&quad).unwrap();
+        assert_eq!(mesh.vertices, boundary);
+        assert_eq!(mesh.boundary_vertex_indices(), &[0, 1, 2, 3, 4]);
+        assert_eq!(mesh.triangles.len(), 6);
+        assert_eq!(projected_points.len(), mesh.vertices.len());
+        assert!(generate_projected_mesh(&quad, &TilerConfig::default()).unwrap().1.is_none());
+
+        let mut used: Vec<u32> = mesh.triangles.clone();
+        used.sort();
+        used.dedup();
+        assert_eq!(used, vec![0, 1, 2, 3]);
+
+        // both triangles face away from the center of the sphere
+        for triangle in mesh.triangles.chunks(3) {
+            let [a, b, c]: [Vector3<f64>; 3] = [0, 1, 2].map(|i| {
+                let (x, y, z) = mesh.vertices[triangle[i] as usize];
+                Vector3::new(x, y, z)
+            });
+            assert!((b - a).cross(&(c - a)).dot(&(a + b + c)) > 0.0);
+        }
+
+        let triangle: Polygon = polygon![(x: 100.0, y: 5.0), (x: 101.0, y: 5.0), (x: 100.5, y: 6.0)];
+        assert_eq!(generate_polygon_feature_mesh(&triangle).unwrap().triangles.len(), 3);
+
+        // a hole disables the fast path, so the quad is triangulated through the projection
+        let holed: Polygon = Polygon::new(
+            quad.exterior().clone(),
+            vec![LineString::from(vec![(100.4, 5.4), (100.6, 5.4), (100.6, 5.6), (100.4, 5.6), (100.4, 5.4)])],
+        );
+        assert!(generate_projected_mesh(&holed, &TilerConfig::default()).unwrap().1.is_some());
+    }
+
+    #[test]
+    fn test_boundary_vertex_indices_match_polygon_rings() {
+        let polygon: Polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (20.0, 0.0), (40.0, 0.0), (40.0, 30.0), (20.0, 30.0), (0.0, 30.0), (0.0, 0.0)]),
+            vec![LineString::from(vec![(15.0, 10.0), (15.0, 20.0), (25.0, 20.0), (25.0, 10.0), (15.0, 10.0)])],
+        );
+        let mesh: PolygonMeshData = generate_polygon_feature_mesh(&polygon).unwrap();
+
+        let ring_points: Vec<(f64, f64, f64)> = polygon.exterior().coords()
+            .chain(polygon.interiors()[0].coords())
+            .map(|c| ll_to_cartesian(c.x, c.y).unwrap())
+            .collect();
+        let boundary: &[u32] = mesh.boundary_vertex_indices();
+        assert_eq!(boundary, (0..ring_points.len() as u32).collect::<Vec<u32>>());
+
+        for (&index, expected) in boundary.iter().zip(&ring_points) {
+            let (x, y, z) = mesh.vertices[index as usize];
+            assert!((x - expected.0).abs() < 1e-12 && (y - expected.1).abs() < 1e-12 && (z - expected.2).abs() < 1e-12);
+        }
+        assert!(mesh.vertices.len() > boundary.len());
+        assert!(mesh.vertices[boundary.len()..].iter().all(|vertex| !ring_points.contains(vertex)));
+
+        let mut corrupted: PolygonMeshData = mesh.clone();
+        corrupted.boundary_indices.push(mesh.vertices.len() as u32);
+        assert!(matches!(corrupted.validate(), Err(GeoTilerError::TriangulationError(_))));
+    }
+
+    #[test]
+    fn test_dedup_triangles_removes_duplicates_and_zero_area_triangles() {
+        let mut mesh: PolygonMeshData = two_triangle_mesh();
+        // the midpoint of vertices 0 and 1, collinear with them
+        mesh.vertices.push((0.5, 0.5, 0.0));
+        mesh.triangles.extend_from_slice(&[
+            2, 1, 0, // duplicate of the first triangle with the opposite winding
+            0, 2, 3, // duplicate of the second triangle, rotated
+            1, 1, 3, // repeats a vertex
+            0, 4, 1, // collinear corners
+        ]);
+
+        dedup_triangles(&mut mesh);
+
+        assert_eq!(mesh.triangles, two_triangle_mesh().triangles);
+        assert_eq!(mesh.vertices.len(), 5);
+
+        dedup_triangles(&mut mesh);
+        assert_eq!(mesh.triangles, two_triangle_mesh().triangles);
+    }
+
+    #[test]
+    fn test_failed_triangulation_is_retried_with_jittered_points() {
+        let polygon: Polygon = polygon![(x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 40.0, y: 0.0), (x: 40.0, y: 30.0), (x: 0.0, y: 30.0)];
+        let config: TilerConfig = TilerConfig::default();
+
+        let mut attempts: Vec<Vec<(f64, f64, f64)>> = Vec::new();
+        let (mesh, projected_points) = generate_projected_mesh_with(&polygon, &config, |mesh_points, ring_lengths, config| {
+            attempts.push(mesh_points.clone());
+            if attempts.len() == 1 {
+                return Err(GeoTilerError::TriangulationError("degenerate projection".to_string()));
+            }
+            triangulate_configured_points(mesh_points, ring_lengths, config)
+        }).unwrap();
+
+        // the first attempt uses the exact lattice and the retry re-collects jittered points
+        let jittered: Vec<Coord<f64>> = fibonacci_sphere_jittered(DEFAULT_FIBONACCI_POINT_COUNT, 1, TRIANGULATION_RETRY_JITTER).unwrap();
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0], get_mesh_points_with_config(&polygon, &config).unwrap());
+        assert_eq!(attempts[1], collect_mesh_points(&polygon, &config, &jittered).unwrap());
+        assert_ne!(attempts[0], attempts[1]);
+
+        // the mesh comes from the successful attempt
+        assert_eq!(mesh.vertices, attempts[1]);
+        assert_eq!(projected_points.map(|points| points.len()), Some(mesh.vertices.len()));
+        assert!(mesh.validate().is_ok());
+
+        // without retries the failure is returned
+        let single: TilerConfig = TilerConfig { triangulation_attempts: 1, ..TilerConfig::default() };
+        let failed: Result<MaybeProjectedMesh, GeoTilerError> = generate_projected_mesh_with(&polygon, &single, |_, _, _| {
+            Err(GeoTilerError::TriangulationError("degenerate projection".to_string()))
+        });
+        assert!(matches!(failed, Err(GeoTilerError::TriangulationError(_))));
+    }
+
+    #[test]
+    fn test_triangles_face_outward_with_either_projection_pole() {
+        let hexagon: Polygon = polygon![(x: 20.0, y: -60.0), (x: 50.0, y: -62.0), (x: 55.0, y: -40.0), (x: 35.0, y: -30.0), (x: 15.0, y: -42.0), (x: 10.0, y: -50.0)];
+        let holed: Polygon = Polygon::new(
+            LineString::from(vec![(-40.0, 10.0), (-10.0, 10.0), (-5.0, 25.0), (-10.0, 40.0), (-40.0, 40.0), (-40.0, 10.0)]),
+            vec![LineString::from(vec![(-30.0, 20.0), (-20.0, 20.0), (-20.0, 30.0), (-30.0, 30.0), (-30.0, 20.0)])],
+        );
+        let quad: Polygon = polygon![(x: 100.0, y: 5.0), (x: 101.0, y: 5.0), (x: 101.0, y: 6.0), (x: 100.0, y: 6.0)];
+
+        for pole in [Pole::South, Pole::North] {
+            let config: TilerConfig = TilerConfig { projection_pole: pole, ..TilerConfig::default() };
+            for polygon in [&hexagon, &holed, &quad] {
+                let mesh: PolygonMeshData = generate_polygon_feature_mesh_with_config(polygon, &config).unwrap();
+                assert!(!mesh.triangles.is_empty());
+
+                for triangle in mesh.triangle_iter() {
+                    let [a, b, c]: [Vector3<f64>; 3] = triangle.map(|index| {
+                        let (x, y, z) = mesh.vertices[index as usize];
+                        Vector3::new(x, y, z)
+                    });
+                    assert!((b - a).cross(&(c - a)).dot(&(a + b + c)) >= 0.0, "{:?} triangle {:?} faces inward", pole, triangle);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use geo::{BooleanOps, MultiPolygon, Polygon, Simplify, Validation};
+use crate::{GeoTilerError, validate_polygon_coordinates};
+
+/// Simplifies a polygon using the Ramer–Douglas–Peucker algorithm.
+///
+/// Dense boundaries, such as coastlines, often carry far more vertices than a coarse tile mesh
+/// needs, which inflates the number of boundary points and triangles. This function removes
+/// vertices that deviate from the simplified boundary by less than `epsilon`, while corners
+/// further away than `epsilon` are kept.
+///
+/// Each ring keeps at least 4 coordinates (3 + closing). If simplification would produce an
+/// invalid polygon (e.g. a self-intersecting ring or a hole crossing the exterior), the input
+/// polygon is returned unchanged instead.
+///
+/// # Arguments
+///
+/// * `polygon` - The polygon to simplify, with coordinates in decimal degrees
+/// * `epsilon` - The maximum distance in degrees a removed vertex may lie from the simplified
+///   boundary. Values less than or equal to 0 leave the polygon unchanged.
+///
+/// # Returns
+///
+/// * `Polygon` - The simplified polygon
+pub fn simplify_polygon(polygon: &Polygon, epsilon: f64) -> Polygon {
+    if epsilon <= 0.0 || !epsilon.is_finite() {
+        return polygon.clone();
+    }
+
+    let simplified: Polygon = polygon.simplify(&epsilon);
+
+    // never trade a valid polygon for an invalid one
+    if !simplified.is_valid() && polygon.is_valid() {
+        log::debug!("Simplification with epsilon {} produced an invalid polygon, keeping the original", epsilon);
+        return polygon.clone();
+    }
+
+    simplified
+}
+
+/// Repairs an invalid polygon, such as a self-intersecting or self-touching fragment left by
+/// floating point noise during clipping.
+///
+/// The polygon is unioned with an empty geometry, which rebuilds its rings from the region they
+/// enclose, the zero-width equivalent of the common `buffer(0)` trick. A ring crossing itself is
+/// split into several polygons at the crossing, so the result is a `MultiPolygon` whose members
+/// can be meshed one by one. Valid polygons are returned unchanged.
+///
+/// # Arguments
+///
+/// * `polygon` - The polygon to repair, with coordinates in decimal degrees
+///
+/// # Returns
+///
+/// * `Ok(MultiPolygon<f64>)` - The valid polygons covering the region enclosed by the input, which
+///   may be empty if the input encloses no area
+/// * `Err(GeoTilerError)` - An `InvalidPolygonError` if the polygon contains non-finite or
+///   out-of-range coordinates, or if the rebuilt geometry is still invalid
+pub fn repair_polygon(polygon: &Polygon) -> Result<MultiPolygon<f64>, GeoTilerError> {
+    validate_polygon_coordinates(polygon)?;
+
+    if polygon.is_valid() {
+        return Ok(MultiPolygon::new(vec![polygon.clone()]));
+    }
+
+    let repaired: MultiPolygon<f64> = polygon.union(&MultiPolygon::new(Vec::new()));
+
+    if !repaired.is_valid() {
+        return Err(GeoTilerError::InvalidPolygonError(
+            "Polygon is invalid and could not be repaired".to_string()
+        ));
+    }
+
+    log::debug!("Repaired an invalid polygon into {} valid polygon(s)", repaired.0.len());
+
+    Ok(repaired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::{coord, polygon, Area, Coord};
+
+    #[test]
+    fn test_simplify_collapses_near_collinear_points() {
+        let polygon: Polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.001),
+            (x: 2.0, y: -0.001),
+            (x: 3.0, y: 0.0005),
+            (x: 4.0, y: 0.0),
+            (x: 4.0, y: 4.0),
+            (x: 0.0, y: 4.0),
+        ];
+
+        let simplified: Polygon = simplify_polygon(&polygon, 0.01);
+        let coords: Vec<Coord<f64>> = simplified.exterior().0.clone();
+
+        assert_eq!(coords.len(), 5);
+        for corner in [coord! {x: 0.0, y: 0.0}, coord! {x: 4.0, y: 0.0}, coord! {x: 4.0, y: 4.0}, coord! {x: 0.0, y: 4.0}] {
+            assert!(coords.contains(&corner));
+        }
+        assert!(simplified.is_valid());
+
+        assert_eq!(simplify_polygon(&polygon, 0.0), polygon);
+    }
+
+    #[test]
+    fn test_repair_polygon_splits_self_touching_and_crossing_rings() {
+        // two triangles touching at (2, 2), traced as a single ring
+        let touching: Polygon = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 2.0, y: 2.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0), (x: 2.0, y: 2.0)];
+        let bowtie: Polygon = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 10.0), (x: 10.0, y: 0.0), (x: 0.0, y: 10.0)];
+
+        for (invalid, area) in [(touching, 8.0), (bowtie, 50.0)] {
+            assert!(!invalid.is_valid());
+
+            let repaired: MultiPolygon<f64> = repair_polygon(&invalid).unwrap();
+            assert!(repaired.is_valid());
+            assert_eq!(repaired.0.len(), 2);
+            assert!((repaired.unsigned_area() - area).abs() < 1e-9);
+        }
+
+        let valid: Polygon = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0)];
+        assert_eq!(repair_polygon(&valid).unwrap(), MultiPolygon::new(vec![valid]));
+
+        let out_of_range: Polygon = polygon![(x: 0.0, y: 0.0), (x: 400.0, y: 0.0), (x: 0.0, y: 4.0)];
+        assert!(matches!(repair_polygon(&out_of_range), Err(GeoTilerError::InvalidPolygonError(_))));
+    }
+}
@@ -1,16 +1,150 @@
-use geo::{Polygon, Coord, LineString, MultiPolygon, BooleanOps};
-use crate::{GeoTilerError, densify_edges};
+use geo::{Polygon, Coord, Geometry, LineString, MultiPoint, MultiPolygon, BooleanOps, BoundingRect, ConvexHull, MapCoordsInPlace, Rect};
+use geo::orient::{Direction, Orient};
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "parallel")]
+use std::thread;
+use crate::{GeoTilerError, DensifyDistance, densify_edges, densify_edges_with_distance, ensure_closed_ring, has_zero_area, planar_polygon_area, validate_polygon_coordinates};
+use crate::geometry::haversine_angle;
 use std::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Default maximum distance in degrees between consecutive points during edge densification.
 const DEFAULT_MAX_DISTANCE_BETWEEN_POINTS: f64 = 1.0;
 
+/// Tolerance in degrees used when comparing tile boundaries and fragment vertices.
+const BOUNDARY_EPSILON: f64 = 1e-6;
+
+/// Tolerance in square degrees above which the intersection of two fragments counts as an overlap.
+const OVERLAP_AREA_EPSILON: f64 = 1e-12;
+
+/// Tolerance in square degrees when comparing tile areas during tessellation checks.
+const TESSELLATION_AREA_EPSILON: f64 = 1e-6;
+
 /// Represents a single tile in a geographic grid system.
 /// Contains the tile's rectangular boundary and any polygon fragments that intersect with it.
-#[derive(Debug, Clone)]
+/// Tiles built by [`generate_grid`] have a closed, counter-clockwise boundary
+/// `[bl, br, tr, tl, bl]` whose area is `step²` square degrees.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Tile {
     pub vertices: Polygon<f64>,
     pub polygons: Vec<Polygon<f64>>,
+
+    /// Index in the grid of the tile each fragment was clipped into, parallel to `polygons`.
+    ///
+    /// The clipping functions and [`merge_grids`] keep both vectors aligned, so this records
+    /// where a fragment came from once fragments of several tiles are merged or exported
+    /// together. Fragments pushed to `polygons` by other means have no recorded index.
+    pub fragment_tile_ids: Vec<usize>,
+}
+
+impl Tile {
+    /// Creates a tile with the given boundary and no polygon fragments.
+    pub fn new(vertices: Polygon<f64>) -> Tile {
+        Tile {
+            vertices,
+            polygons: Vec::new(),
+            fragment_tile_ids: Vec::new(),
+        }
+    }
+
+    /// Removes every polygon fragment from the tile while keeping its boundary.
+    pub fn clear(&mut self) {
+        self.polygons.clear();
+        self.fragment_tile_ids.clear();
+    }
+
+    /// Returns the outline of the tile without the closing duplicate vertex stored by
+    /// `geo::Polygon`.
+    ///
+    /// For tiles produced by [`generate_grid`] this is the four corners in the order bottom-left,
+    /// bottom-right, top-right, top-left, which is convenient for drawing grid overlays.
+    pub fn boundary_linestring(&self) -> LineString<f64> {
+        let mut coords: Vec<Coord<f64>> = self.vertices.exterior().0.clone();
+
+        if coords.len() > 1 && coords.first() == coords.last() {
+            coords.pop();
+        }
+
+        LineString::new(coords)
+    }
+
+    /// Computes the convex hull of every vertex of the tile's polygon fragments.
+    ///
+    /// The hull is a simplified footprint of the tile's content, useful for broad-phase culling.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Polygon<f64>)` - The convex hull of the fragment vertices, in decimal degrees
+    /// * `None` - If the tile has no polygon fragments
+    pub fn fragments_convex_hull(&self) -> Option<Polygon<f64>> {
+        if self.polygons.is_empty() {
+            return None;
+        }
+
+        let points: MultiPoint<f64> = self.polygons.iter()
+            .flat_map(|polygon| polygon.exterior().points())
+            .collect();
+
+        Some(points.convex_hull())
+    }
+
+    /// Checks whether any two polygon fragments of the tile overlap.
+    ///
+    /// Fragments of overlapping source features clipped into the same tile overlap each other,
+    /// which double-counts their common area. Every pair of fragments is intersected, and the
+    /// pair overlaps when the planar area of the intersection exceeds a small tolerance, so that
+    /// fragments merely sharing an edge or a vertex are not reported.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `true` if at least one pair of fragments overlaps
+    pub fn has_overlapping_fragments(&self) -> bool {
+        self.polygons.iter().enumerate().any(|(i, a)| {
+            self.polygons[i + 1..].iter().any(|b| {
+                let overlap: f64 = a.intersection(b).0.iter().map(planar_polygon_area).sum();
+                overlap > OVERLAP_AREA_EPSILON
+            })
+        })
+    }
+
+    /// Merges the polygon fragments of the tile into non-overlapping pieces.
+    ///
+    /// The fragments are unioned together, so overlapping fragments become a single fragment and
+    /// disjoint fragments are kept apart. The resulting fragments are oriented like the output of
+    /// [`clip_polygon_to_tiles`], with a counter-clockwise exterior and clockwise interiors.
+    ///
+    /// `fragment_tile_ids` stays parallel to `polygons` when every fragment had the same recorded
+    /// index, which is always the case for a tile clipped by this crate, and is cleared otherwise
+    /// since the merged fragments have no single origin.
+    pub fn union_fragments(&mut self) {
+        if self.polygons.len() < 2 {
+            return;
+        }
+
+        let merged: MultiPolygon<f64> = self.polygons.iter()
+            .fold(MultiPolygon::new(Vec::new()), |merged, polygon| merged.union(polygon));
+
+        let common_id: Option<usize> = match self.fragment_tile_ids.first() {
+            Some(&id) if self.fragment_tile_ids.len() == self.polygons.len()
+                && self.fragment_tile_ids.iter().all(|&other| other == id) => Some(id),
+            _ => None,
+        };
+
+        self.polygons = merged.into_iter()
+            .map(|polygon| polygon.orient(Direction::Default))
+            .collect();
+        self.fragment_tile_ids = match common_id {
+            Some(id) => vec![id; self.polygons.len()],
+            None => Vec::new(),
+        };
+    }
+}
+
+impl Default for Tile {
+    /// Creates a tile with an empty boundary and no polygon fragments.
+    fn default() -> Self {
+        Tile::new(Polygon::new(LineString::new(Vec::new()), Vec::new()))
+    }
 }
 
 impl fmt::Display for Tile {
@@ -21,11 +155,265 @@ impl fmt::Display for Tile {
     }
 }
 
+/// Serialized form of a polygon: its rings, exterior first, each as an array of `[x, y]` pairs.
+type PolygonRings = Vec<Vec<[f64; 2]>>;
+
+/// Serialized form of a [`Tile`], with the boundary and every fragment encoded as
+/// [`PolygonRings`], in the same layout as GeoJSON polygon coordinates.
+#[derive(Serialize, Deserialize)]
+struct TileRepr {
+    vertices: PolygonRings,
+    polygons: Vec<PolygonRings>,
+    #[serde(default)]
+    fragment_tile_ids: Vec<usize>,
+}
+
+impl Serialize for Tile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TileRepr {
+            vertices: polygon_to_rings(&self.vertices),
+            polygons: self.polygons.iter().map(polygon_to_rings).collect(),
+            fragment_tile_ids: self.fragment_tile_ids.clone(),
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr: TileRepr = TileRepr::deserialize(deserializer)?;
+
+        Ok(Tile {
+            vertices: rings_to_polygon(repr.vertices).map_err(de::Error::custom)?,
+            polygons: repr.polygons.into_iter()
+                .map(rings_to_polygon)
+                .collect::<Result<_, _>>()
+                .map_err(de::Error::custom)?,
+            fragment_tile_ids: repr.fragment_tile_ids,
+        })
+    }
+}
+
+fn polygon_to_rings(polygon: &Polygon<f64>) -> PolygonRings {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(|ring| ring.coords().map(|coord| [coord.x, coord.y]).collect())
+        .collect()
+}
+
+fn rings_to_polygon(rings: PolygonRings) -> Result<Polygon<f64>, &'static str> {
+    let mut rings = rings.into_iter().map(|ring| {
+        LineString::new(ring.into_iter().map(|[x, y]| Coord { x, y }).collect())
+    });
+
+    let exterior: LineString<f64> = rings.next().ok_or("polygon must have an exterior ring")?;
+
+    Ok(Polygon::new(exterior, rings.collect()))
+}
+
+/// A grid of tiles with a precomputed lookup from lattice cells to tiles.
+///
+/// [`clip_polygon_to_tiles`] intersects every polygon with every tile of a flat `Vec<Tile>`,
+/// which is `O(tiles)` per feature. A `Grid` records, for each `step`-degree cell of the lattice
+/// starting at (-180°, -90°), the index of the tile covering it, so that [`Grid::clip`] only
+/// intersects a polygon with the tiles under its bounding box. Cells are addressed by row, counted
+/// from the south, and column, counted from the west, which keeps the index arithmetic of the
+/// [`generate_grid`] layout in one place.
+///
+/// Tile indices, including those recorded in `fragment_tile_ids`, are positions in the wrapped
+/// vector, so a grid clipped through a `Grid` holds exactly the same fragments as one clipped
+/// with [`clip_polygon_to_tiles`]. Like `Vec<Tile>`, a `Grid` is `Send` and `Sync`: it can be
+/// moved to a worker thread or shared for reading, while clipping requires exclusive access.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    tiles: Vec<Tile>,
+    step: usize,
+    /// Index in `tiles` of the tile covering each cell, row by row from the south-west corner
+    cells: Vec<Option<usize>>,
+}
+
+impl Grid {
+    /// Creates a grid of empty tiles, laid out like [`generate_grid`].
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - The angular step size in degrees for both longitude and latitude divisions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeoTilerError::GridGenerationError` under the same conditions as [`generate_grid`].
+    pub fn new(step: usize) -> Result<Grid, GeoTilerError> {
+        Grid::from_tiles(generate_grid(step)?)
+    }
+
+    /// Wraps existing tiles, such as a grid loaded from disk or filtered with
+    /// [`generate_grid_filtered`], into a `Grid`.
+    ///
+    /// The tiles may come in any order and need not cover the whole sphere; cells without a tile
+    /// are simply skipped when clipping.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiles` - The tiles of the grid, which keep their fragments and their order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeoTilerError::GridGenerationError` if `tiles` is empty or irregular (see
+    /// [`infer_grid_step`]), if the step is not valid for [`generate_grid`], or if a tile does not
+    /// sit on the lattice starting at (-180°, -90°) within the longitude/latitude domain.
+    pub fn from_tiles(tiles: Vec<Tile>) -> Result<Grid, GeoTilerError> {
+        let step: usize = infer_grid_step(&tiles).ok_or_else(|| GeoTilerError::GridGenerationError(
+            "Grid is empty or irregular: tiles must be equally sized, aligned and non-overlapping".to_string()
+        ))?;
+        validate_grid_step(step)?;
+
+        let rows: usize = 180 / step;
+        let columns: usize = 360 / step;
+        let mut cells: Vec<Option<usize>> = vec![None; rows * columns];
+
+        for (index, tile) in tiles.iter().enumerate() {
+            let Some(extent) = tile.vertices.bounding_rect() else { continue };
+            let column: f64 = (extent.min().x + 180.0) / step as f64;
+            let row: f64 = (extent.min().y + 90.0) / step as f64;
+
+            if column.fract() != 0.0 || row.fract() != 0.0
+                || column < 0.0 || column >= columns as f64 || row < 0.0 || row >= rows as f64 {
+                return Err(GeoTilerError::GridGenerationError(
+                    format!("Tile {} with corner ({}, {}) is not on the {}-degree lattice of the globe", index, extent.min().x, extent.min().y, step)
+                ));
+            }
+
+            cells[row as usize * columns + column as usize] = Some(index);
+        }
+
+        Ok(Grid { tiles, step, cells })
+    }
+
+    /// Returns the step size in degrees of the grid.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the tiles of the grid, in the order they were given.
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    /// Unwraps the grid into its tiles, in the order they were given.
+    pub fn into_tiles(self) -> Vec<Tile> {
+        self.tiles
+    }
+
+    /// Returns the tile covering a cell of the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the cell, 0 being the southernmost row
+    /// * `column` - The column of the cell, 0 being the westernmost column
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Tile>` - The tile, or `None` if the cell is outside the grid or has no tile
+    pub fn get(&self, row: usize, column: usize) -> Option<&Tile> {
+        let index: usize = self.tile_index(row, column)?;
+
+        self.tiles.get(index)
+    }
+
+    /// Returns a mutable reference to the tile containing a point.
+    ///
+    /// A point on the edge between two tiles belongs to the tile to its east or north, except on
+    /// the +180° meridian and the north pole, which belong to the last column and row.
+    ///
+    /// # Arguments
+    ///
+    /// * `coord` - The point as (longitude, latitude) in decimal degrees
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&mut Tile>` - The tile, or `None` if the point is outside the valid coordinate
+    ///   ranges or its cell has no tile
+    pub fn get_mut_by_coord(&mut self, coord: Coord<f64>) -> Option<&mut Tile> {
+        if !(-180.0..=180.0).contains(&coord.x) || !(-90.0..=90.0).contains(&coord.y) {
+            return None;
+        }
+
+        let row: usize = self.row_of(coord.y);
+        let column: usize = self.column_of(coord.x);
+        let index: usize = self.tile_index(row, column)?;
+
+        self.tiles.get_mut(index)
+    }
+
+    /// Clips a polygon to the tiles under its bounding box.
+    ///
+    /// The fragments are identical to those added by [`clip_polygon_to_tiles`], since tiles
+    /// outside the polygon's bounding box cannot intersect it.
+    ///
+    /// # Arguments
+    ///
+    /// * `polygon` - The polygon to be clipped against the grid.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of tiles the polygon was intersected with
+    /// * `Err(GeoTilerError)` - Under the same conditions as [`clip_polygon_to_tiles`], leaving
+    ///   the grid unchanged
+    pub fn clip(&mut self, polygon: &Polygon<f64>) -> Result<usize, GeoTilerError> {
+        validate_clip_polygon(polygon)?;
+
+        let Some(extent) = polygon.bounding_rect() else { return Ok(0) };
+        let (first_row, last_row) = (self.row_of(extent.min().y), self.row_of(extent.max().y));
+        let (first_column, last_column) = (self.column_of(extent.min().x), self.column_of(extent.max().x));
+
+        let mut visited: usize = 0;
+        for row in first_row..=last_row {
+            for column in first_column..=last_column {
+                let Some(index) = self.tile_index(row, column) else { continue };
+
+                clip_polygon_to_tile(
+                    &mut self.tiles[index],
+                    index,
+                    polygon,
+                    DensifyDistance::Degrees(DEFAULT_MAX_DISTANCE_BETWEEN_POINTS)
+                );
+                visited += 1;
+            }
+        }
+
+        Ok(visited)
+    }
+
+    /// Returns the index in `tiles` of the tile covering a cell, if any.
+    fn tile_index(&self, row: usize, column: usize) -> Option<usize> {
+        let columns: usize = 360 / self.step;
+        if column >= columns {
+            return None;
+        }
+
+        *self.cells.get(row * columns + column)?
+    }
+
+    /// Returns the row of the cells containing a latitude, clamped to the grid.
+    fn row_of(&self, latitude: f64) -> usize {
+        let rows: usize = 180 / self.step;
+
+        (((latitude + 90.0) / self.step as f64).floor().max(0.0) as usize).min(rows - 1)
+    }
+
+    /// Returns the column of the cells containing a longitude, clamped to the grid.
+    fn column_of(&self, longitude: f64) -> usize {
+        let columns: usize = 360 / self.step;
+
+        (((longitude + 180.0) / self.step as f64).floor().max(0.0) as usize).min(columns - 1)
+    }
+}
+
 /// Generates a grid of tiles covering the entire Earth's surface using longitude and latitude coordinates.
 ///
 /// This function creates a uniform grid by dividing the Earth's surface into rectangular tiles
 /// of equal angular size. The grid covers longitude from -180° to 180° and latitude from -90° to 90°.
-/// Each tile is represented as a quadrilateral with four corner vertices.
+/// Each tile is represented as a quadrilateral with four corner vertices, ordered bottom-left,
+/// bottom-right, top-right, top-left and closed back on the bottom-left corner.
 ///
 /// # Arguments
 ///
@@ -48,7 +436,233 @@ impl fmt::Display for Tile {
 /// * Longitude: -180° to +180° (360° total)
 /// * Latitude: -90° to +90° (180° total)
 /// * Total tiles: (360 / step) × (180 / step)
+///
+/// The valid steps are the 18 divisors of 180 (1, 2, 3, 4, 5, 6, 9, 10, 12, 15, 18, 20, 30, 36,
+/// 45, 60, 90 and 180). For each of them the corner coordinates are whole degrees computed in
+/// integer arithmetic, so the westernmost and easternmost columns end exactly on -180° and 180°
+/// and the southernmost and northernmost rows end exactly on -90° and 90°, without any gap or
+/// overlap between tiles.
+///
+/// # Antimeridian
+///
+/// The first column of tiles starts on -180° and the last one ends on +180°. The grid does not
+/// wrap, so a region touching or lying on the +180° meridian is clipped into the last column only,
+/// and one on the -180° meridian into the first column only; nothing is dropped. Polygons crossing
+/// the antimeridian must be split into a part east of -180° and a part west of +180°, as required
+/// by RFC 7946 for GeoJSON, since coordinates beyond ±180° are rejected when clipping. The two
+/// parts then land in the first and last columns, which [`neighbor_indices`] and
+/// [`check_boundary_consistency`] treat as adjacent.
 pub fn generate_grid(step: usize) -> Result<Vec<Tile>, GeoTilerError> {
+    let tiles = grid_iter(step)?;
+
+    Ok(tiles.collect())
+}
+
+/// Lazily generates the tiles of a grid covering the entire Earth's surface.
+///
+/// This yields exactly the same tiles, in the same order, as [`generate_grid`], but builds each
+/// tile on demand instead of allocating the whole grid up front, which suits streaming pipelines
+/// working with fine grids.
+///
+/// # Arguments
+///
+/// * `step` - The angular step size in degrees for both longitude and latitude divisions.
+///
+/// # Returns
+///
+/// * `Result<impl Iterator<Item = Tile>, GeoTilerError>` - An iterator over the tiles, or an error
+///   if the step size is invalid. The step is validated once, before any tile is yielded.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` under the same conditions as [`generate_grid`].
+pub fn grid_iter(step: usize) -> Result<impl Iterator<Item = Tile>, GeoTilerError> {
+    validate_grid_step(step)?;
+
+    let tiles = (-180..180).step_by(step).flat_map(move |i: i32| {
+        (-90..90).step_by(step).map(move |j: i32| {
+            let bl: Coord<f64> = Coord { x: i as f64, y: j as f64 };
+            let br: Coord<f64> = Coord { x: (i + step as i32) as f64, y: j as f64 };
+            let tl: Coord<f64> = Coord { x: i as f64, y: (j + step as i32) as f64 };
+            let tr: Coord<f64> = Coord { x: (i + step as i32) as f64, y: (j + step as i32) as f64 };
+
+            Tile::new(Polygon::new(LineString::new(vec![bl, br, tr, tl, bl]), vec![]))
+        })
+    });
+
+    Ok(tiles)
+}
+
+/// Generates a grid of tiles covering the entire Earth's surface, with tile edges aligned to an
+/// arbitrary origin instead of -180°/-90°.
+///
+/// Meridians of the grid fall on `origin_lon + k · step` and parallels on `origin_lat + k · step`
+/// for every integer `k`, so the origin only matters modulo `step` and may lie anywhere. Tiles are
+/// never allowed past ±180° or ±90°, which clipping would reject: the column that wraps around
+/// the antimeridian is split into a tile ending on +180° and one starting on -180°, and the rows
+/// touching the poles are cut short at ±90°. These edge tiles are therefore narrower or shorter
+/// than `step`, while the grid still covers the sphere without gap or overlap. An origin aligned
+/// with -180°/-90° produces the same tiles as [`generate_grid`].
+///
+/// Tiles are ordered column by column from west to east, and from south to north within a column.
+/// The index layout of [`generate_grid`] does not hold for an offset grid, which has one more row
+/// and column, so index-based helpers such as [`neighbor_indices`] must not be used with it.
+///
+/// # Arguments
+///
+/// * `step` - The angular step size in degrees for both longitude and latitude divisions.
+/// * `origin_lon` - The longitude in degrees of a corner of the grid
+/// * `origin_lat` - The latitude in degrees of a corner of the grid
+///
+/// # Returns
+///
+/// * `Result<Vec<Tile>, GeoTilerError>` - A vector containing all generated tiles with empty
+///   polygons, or an error if the parameters are invalid.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` under the same conditions as [`generate_grid`],
+/// or if either origin coordinate is not a finite number.
+pub fn generate_grid_with_origin(step: usize, origin_lon: f64, origin_lat: f64) -> Result<Vec<Tile>, GeoTilerError> {
+    validate_grid_step(step)?;
+
+    if !origin_lon.is_finite() || !origin_lat.is_finite() {
+        return Err(GeoTilerError::GridGenerationError(
+            format!("Grid origin must be finite, got ({}, {})", origin_lon, origin_lat)
+        ));
+    }
+
+    let longitudes: Vec<f64> = offset_grid_edges(-180.0, 180.0, origin_lon, step as f64);
+    let latitudes: Vec<f64> = offset_grid_edges(-90.0, 90.0, origin_lat, step as f64);
+
+    let mut tiles: Vec<Tile> = Vec::with_capacity((longitudes.len() - 1) * (latitudes.len() - 1));
+    for x in longitudes.windows(2) {
+        for y in latitudes.windows(2) {
+            let bl: Coord<f64> = Coord { x: x[0], y: y[0] };
+            let br: Coord<f64> = Coord { x: x[1], y: y[0] };
+            let tr: Coord<f64> = Coord { x: x[1], y: y[1] };
+            let tl: Coord<f64> = Coord { x: x[0], y: y[1] };
+
+            tiles.push(Tile::new(Polygon::new(LineString::new(vec![bl, br, tr, tl, bl]), vec![])));
+        }
+    }
+
+    Ok(tiles)
+}
+
+/// Returns the sorted edges of an offset grid along one axis, from `min` to `max` inclusive.
+///
+/// Lines fall on `origin + k · step`. A line within `BOUNDARY_EPSILON` of `min` or `max` is merged
+/// into it, so that no sliver tile is produced.
+fn offset_grid_edges(min: f64, max: f64, origin: f64, step: f64) -> Vec<f64> {
+    let mut offset: f64 = (origin - min).rem_euclid(step);
+    if step - offset < BOUNDARY_EPSILON {
+        offset = 0.0;
+    }
+
+    let mut edges: Vec<f64> = vec![min];
+    let mut k: usize = 0;
+    loop {
+        let edge: f64 = min + offset + k as f64 * step;
+        if edge > max - BOUNDARY_EPSILON {
+            break;
+        }
+        if edge > min + BOUNDARY_EPSILON {
+            edges.push(edge);
+        }
+        k += 1;
+    }
+    edges.push(max);
+
+    edges
+}
+
+/// Generates a grid of tiles covering the entire Earth's surface, keeping only the tiles accepted
+/// by a predicate.
+///
+/// This produces the same tiles as [`generate_grid`], in the same order, minus those for which
+/// `keep` returns false. Tiles are filtered as they are generated, so a regional dataset never
+/// allocates or clips against the rest of the planet. The result is not a complete grid, so
+/// tile indices differ from those of [`generate_grid`].
+///
+/// # Arguments
+///
+/// * `step` - The angular step size in degrees for both longitude and latitude divisions.
+/// * `keep` - Returns true for the tiles to retain, for example those overlapping a region.
+///
+/// # Returns
+///
+/// * `Result<Vec<Tile>, GeoTilerError>` - The retained tiles with empty polygons, or an error if
+///   the step size is invalid.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` under the same conditions as [`generate_grid`].
+pub fn generate_grid_filtered(step: usize, keep: impl Fn(&Tile) -> bool) -> Result<Vec<Tile>, GeoTilerError> {
+    let tiles = grid_iter(step)?;
+
+    Ok(tiles.filter(|tile| keep(tile)).collect())
+}
+
+/// Generates a grid of tiles covering the entire Earth's surface, replacing the rows of tiles
+/// beyond a latitude with a single cap tile around each pole.
+///
+/// Near the poles, the tiles of [`generate_grid`] become thin slivers on the sphere which
+/// triangulate poorly. Here every tile north of `cap_latitude` is replaced by one tile spanning
+/// the full longitude range from `cap_latitude` to 90°, and every tile south of `-cap_latitude`
+/// by one tile spanning from -90° to `-cap_latitude`. A cap tile's boundary runs along the whole
+/// cap latitude ring, from -180° to 180°, and closes along the pole, in the same
+/// `[bl, br, tr, tl, bl]` order as the other tiles.
+///
+/// The south cap comes first, followed by the remaining tiles in [`generate_grid`] order and the
+/// north cap last. Since the cap tiles are not square, such a grid is not recognized by
+/// [`infer_grid_step`], and [`neighbor_indices`] does not apply to it.
+///
+/// # Arguments
+///
+/// * `step` - The angular step size in degrees for both longitude and latitude divisions.
+/// * `cap_latitude` - The latitude in degrees beyond which each polar region becomes a single
+///   tile. It must be a multiple of `step` between 0 and 90, both excluded.
+///
+/// # Returns
+///
+/// * `Result<Vec<Tile>, GeoTilerError>` - The two cap tiles and the tiles between them, with
+///   empty polygons, or an error if the parameters are invalid.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` under the same conditions as [`generate_grid`],
+/// or if `cap_latitude` is not a multiple of `step` strictly between 0 and 90.
+pub fn generate_grid_with_caps(step: usize, cap_latitude: usize) -> Result<Vec<Tile>, GeoTilerError> {
+    validate_grid_step(step)?;
+
+    if cap_latitude == 0 || cap_latitude >= 90 || !cap_latitude.is_multiple_of(step) {
+        return Err(GeoTilerError::GridGenerationError(
+            format!("Cap latitude {} must be a multiple of the step size {} between 0 and 90 degrees", cap_latitude, step)
+        ));
+    }
+
+    let cap: f64 = cap_latitude as f64;
+    let cap_tile = |bottom: f64, top: f64| -> Tile {
+        let bl: Coord<f64> = Coord { x: -180.0, y: bottom };
+        let br: Coord<f64> = Coord { x: 180.0, y: bottom };
+        let tl: Coord<f64> = Coord { x: -180.0, y: top };
+        let tr: Coord<f64> = Coord { x: 180.0, y: top };
+
+        Tile::new(Polygon::new(LineString::new(vec![bl, br, tr, tl, bl]), vec![]))
+    };
+
+    let mut tiles: Vec<Tile> = vec![cap_tile(-90.0, -cap)];
+    tiles.extend(grid_iter(step)?.filter(|tile| {
+        tile.vertices.exterior().coords().all(|coord| coord.y.abs() <= cap)
+    }));
+    tiles.push(cap_tile(cap, 90.0));
+
+    Ok(tiles)
+}
+
+/// Checks that a grid step size produces a complete, valid grid.
+pub(crate) fn validate_grid_step(step: usize) -> Result<(), GeoTilerError> {
     if step == 0 {
         return Err(GeoTilerError::GridGenerationError(
             "Step size must be greater than 0".to_string()
@@ -73,114 +687,1874 @@ pub fn generate_grid(step: usize) -> Result<Vec<Tile>, GeoTilerError> {
         ));
     }
 
-    let mut grid: Vec<Tile> = Vec::new();
+    Ok(())
+}
 
-    for i in (-180..180).step_by(step) {
-        for j in (-90..90).step_by(step) {
-            let bl: Coord<f64> = Coord { x: i as f64, y: j as f64 };
-            let br: Coord<f64> = Coord { x: (i + step as i32) as f64, y: j as f64 };
-            let tl: Coord<f64> = Coord { x: i as f64, y: (j + step as i32) as f64 };
-            let tr: Coord<f64> = Coord { x: (i + step as i32) as f64, y: (j + step as i32) as f64 };
+/// Infers the angular step size of a grid of tiles.
+///
+/// This function inspects the extent of every tile and returns the common step size if the
+/// grid is coherent, which is useful to confirm that tiles loaded from disk form the kind of
+/// grid produced by [`generate_grid`] before clipping more polygons into them.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles to inspect.
+///
+/// # Returns
+///
+/// * `Some(usize)` - The step size in degrees shared by every tile.
+/// * `None` - If the grid is empty or irregular, meaning that a tile is not a square with a
+///   whole-degree side, tiles have different sizes, tiles are not aligned on the same lattice,
+///   or two tiles cover the same cell.
+pub fn infer_grid_step(grid: &[Tile]) -> Option<usize> {
+    let first_extent: Rect<f64> = grid.first()?.vertices.bounding_rect()?;
+    let step: f64 = first_extent.width();
+
+    if step < 1.0 || step.fract() != 0.0 {
+        return None;
+    }
+
+    let mut cells: HashSet<(i64, i64)> = HashSet::with_capacity(grid.len());
+    for tile in grid {
+        let extent: Rect<f64> = tile.vertices.bounding_rect()?;
+
+        if extent.width() != step || extent.height() != step {
+            return None;
+        }
 
-            let tile: Tile = Tile {
-                vertices: Polygon::new(LineString::new(vec![bl, br, tl, tr]), vec![]),
-                polygons: Vec::new()
-            };
+        // tiles must sit on the same lattice as the first tile
+        let column: f64 = (extent.min().x - first_extent.min().x) / step;
+        let row: f64 = (extent.min().y - first_extent.min().y) / step;
+        if column.fract() != 0.0 || row.fract() != 0.0 {
+            return None;
+        }
 
-            grid.push(tile);
+        // tiles must not overlap
+        if !cells.insert((column as i64, row as i64)) {
+            return None;
         }
     }
 
-    Ok(grid)
+    Some(step as usize)
 }
 
-/// Clips a polygon to a grid of tiles and stores the resulting intersections in each tile.
+/// Checks numerically that a grid of tiles tessellates the whole longitude/latitude domain.
 ///
-/// This function takes a polygon and computes its intersection with each tile in the grid.
-/// The resulting polygon fragments are stored in each tile's `polygons` vector. 
+/// The planar areas of the tiles, in square degrees, must add up to 360 × 180, and no two tiles
+/// may overlap. Every tile must be an axis-aligned rectangle whose corners lie on the lattice of
+/// `step`-degree cells starting at (-180°, -90°), which holds for grids from [`generate_grid`] and
+/// [`generate_grid_with_caps`]. Overlaps are found by marking the lattice cells each tile covers,
+/// so together with the area check this also rules out gaps.
 ///
 /// # Arguments
 ///
-/// * `grid` - A mutable reference to a vector of tiles. Each tile's `polygons` vector will be
-///            updated with any intersection fragments.
-/// * `polygon` - The polygon to be clipped against the tile grid.
-pub fn clip_polygon_to_tiles(grid: &mut Vec<Tile>, polygon: &Polygon<f64>) -> Result<(), GeoTilerError> {
-    
-    let vertex_count: usize = polygon.exterior().coords().count();
-    if vertex_count < 4 {  
-        return Err(GeoTilerError::InvalidPolygonError(
-            format!("Polygon must have at least 3 vertices, found {}", vertex_count - 1)
-        ));
-    }
+/// * `grid` - The tiles to check.
+/// * `step` - The step size in degrees of the lattice the tiles are aligned on.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` if:
+/// * `step` is not a valid step for [`generate_grid`]
+/// * a tile is not an axis-aligned rectangle on the lattice, or extends outside the domain
+/// * two tiles overlap
+/// * the tile areas do not add up to 360 × 180 square degrees
+pub fn validate_grid_tessellation(grid: &[Tile], step: usize) -> Result<(), GeoTilerError> {
+    validate_grid_step(step)?;
 
-    for coord in polygon.exterior().coords() {
-        if !coord.x.is_finite() || !coord.y.is_finite() {
-            return Err(GeoTilerError::InvalidPolygonError(
-                "Polygon contains NaN or infinite coordinates".to_string()
+    let columns: usize = 360 / step;
+    let rows: usize = 180 / step;
+    let step_f: f64 = step as f64;
+
+    // index of the tile covering each lattice cell, laid out like generate_grid
+    let mut covering_tiles: Vec<Option<usize>> = vec![None; columns * rows];
+    let mut total_area: f64 = 0.0;
+
+    for (index, tile) in grid.iter().enumerate() {
+        let extent: Rect<f64> = tile.vertices.bounding_rect().ok_or_else(|| GeoTilerError::GridGenerationError(
+            format!("Tile {} has an empty boundary", index)
+        ))?;
+
+        let area: f64 = planar_polygon_area(&tile.vertices);
+        if (area - extent.width() * extent.height()).abs() > TESSELLATION_AREA_EPSILON {
+            return Err(GeoTilerError::GridGenerationError(
+                format!("Tile {} is not an axis-aligned rectangle", index)
             ));
         }
-    }
+        total_area += area;
 
-    for tile in grid {
-        let resulting_polygons: MultiPolygon<f64> = tile.vertices.intersection(polygon);
+        let lattice_index = |value: f64, origin: f64, count: usize| -> Option<usize> {
+            let position: f64 = (value - origin) / step_f;
+            let rounded: f64 = position.round();
+
+            ((position - rounded).abs() <= BOUNDARY_EPSILON && rounded >= 0.0 && rounded <= count as f64)
+                .then_some(rounded as usize)
+        };
+        let off_lattice = || GeoTilerError::GridGenerationError(
+            format!("Tile {} does not lie on the {}-degree lattice of the domain", index, step)
+        );
+        let min_column: usize = lattice_index(extent.min().x, -180.0, columns).ok_or_else(off_lattice)?;
+        let max_column: usize = lattice_index(extent.max().x, -180.0, columns).ok_or_else(off_lattice)?;
+        let min_row: usize = lattice_index(extent.min().y, -90.0, rows).ok_or_else(off_lattice)?;
+        let max_row: usize = lattice_index(extent.max().y, -90.0, rows).ok_or_else(off_lattice)?;
 
-        for mut rp in resulting_polygons {
-            densify_edges(&mut rp, DEFAULT_MAX_DISTANCE_BETWEEN_POINTS);
-            tile.polygons.push(rp);
+        for column in min_column..max_column {
+            for row in min_row..max_row {
+                let cell: &mut Option<usize> = &mut covering_tiles[column * rows + row];
+                if let Some(other) = *cell {
+                    return Err(GeoTilerError::GridGenerationError(
+                        format!("Tiles {} and {} overlap", other, index)
+                    ));
+                }
+                *cell = Some(index);
+            }
         }
     }
 
+    if (total_area - 360.0 * 180.0).abs() > TESSELLATION_AREA_EPSILON {
+        return Err(GeoTilerError::GridGenerationError(
+            format!("Tiles cover {} square degrees instead of {}", total_area, 360.0 * 180.0)
+        ));
+    }
+
     Ok(())
 }
 
-/// Clamps all polygons in each tile to ensure their coordinates stay within the tile boundaries.
+/// Clips a polygon to a grid of tiles and stores the resulting intersections in each tile.
 ///
-/// This function addresses floating-point precision errors that can occur during polygon intersection
-/// operations, which may result in polygon vertices slightly extending beyond their containing tile's
-/// boundaries. Such precision errors can prevent proper triangulation of the polygons.
+/// This function takes a polygon and computes its intersection with each tile in the grid.
+/// The resulting polygon fragments are stored in each tile's `polygons` vector, with a
+/// counter-clockwise exterior and clockwise interior rings regardless of the input's winding.
+/// Fragment edges are densified so that consecutive points are at most 1 degree apart.
 ///
 /// # Arguments
 ///
-/// * `tiles` - A mutable reference to a vector of tiles. Each tile's polygons will have their
-///             coordinates clamped to the tile's boundary limits.
-pub fn clamp_polygons(tiles: &mut Vec<Tile>) {
-    for tile in tiles {
+/// * `grid` - A mutable reference to a vector of tiles. Each tile's `polygons` vector will be
+///            updated with any intersection fragments.
+/// * `polygon` - The polygon to be clipped against the tile grid.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::InvalidPolygonError`, leaving the grid unchanged, if the polygon has
+/// fewer than 3 vertices, contains non-finite or out-of-range coordinates, or has zero area (see
+/// [`has_zero_area`]).
+pub fn clip_polygon_to_tiles(grid: &mut Vec<Tile>, polygon: &Polygon<f64>) -> Result<(), GeoTilerError> {
+    clip_polygon_to_tiles_with_distance(
+        grid,
+        polygon,
+        DensifyDistance::Degrees(DEFAULT_MAX_DISTANCE_BETWEEN_POINTS)
+    )
+}
+
+/// Clips a polygon to a grid of tiles, densifying the resulting fragments with the given
+/// maximum distance between points.
+///
+/// This behaves like [`clip_polygon_to_tiles`] but lets the caller choose the densification
+/// spacing, either in degrees or in kilometers.
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles. Each tile's `polygons` vector will be
+///   updated with any intersection fragments.
+/// * `polygon` - The polygon to be clipped against the tile grid.
+/// * `max_distance` - The maximum distance between consecutive points along fragment edges.
+pub fn clip_polygon_to_tiles_with_distance(
+    grid: &mut Vec<Tile>,
+    polygon: &Polygon<f64>,
+    max_distance: DensifyDistance
+) -> Result<(), GeoTilerError> {
+    let mut polygon: Polygon<f64> = polygon.clone();
+    ensure_closed_ring(&mut polygon);
+    validate_clip_polygon(&polygon)?;
+
+    for (index, tile) in (0..).zip(grid) {
+        clip_polygon_to_tile(tile, index, &polygon, max_distance);
+    }
+
+    Ok(())
+}
+
+/// Clips every polygon of a `geo` geometry to a grid of tiles.
+///
+/// This is a single entry point for data coming from the `geo` ecosystem: a `Polygon` is clipped
+/// with [`clip_polygon_to_tiles`], each member of a `MultiPolygon` is clipped in order, and the
+/// members of a `GeometryCollection` are handled recursively. Every polygon is validated before
+/// any is clipped, so the grid is left unchanged when an error is returned.
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles. Each tile's `polygons` vector will be
+///   updated with any intersection fragments.
+/// * `geom` - The geometry whose polygons are clipped against the tile grid.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::InvalidPolygonError` if the geometry, or a member of a collection, is
+/// not a `Polygon`, `MultiPolygon` or `GeometryCollection`, and any error returned by
+/// [`clip_polygon_to_tiles`] for one of its polygons.
+pub fn clip_geometry_to_tiles(grid: &mut Vec<Tile>, geom: &Geometry<f64>) -> Result<(), GeoTilerError> {
+    let mut polygons: Vec<&Polygon<f64>> = Vec::new();
+    collect_geometry_polygons(geom, &mut polygons)?;
+
+    for polygon in &polygons {
+        validate_clip_polygon(polygon)?;
+    }
+
+    for polygon in polygons {
+        clip_polygon_to_tiles(grid, polygon)?;
+    }
+
+    Ok(())
+}
+
+/// Collects the polygons of a geometry, recursing into geometry collections.
+fn collect_geometry_polygons<'a>(geom: &'a Geometry<f64>, polygons: &mut Vec<&'a Polygon<f64>>) -> Result<(), GeoTilerError> {
+    let unsupported: &str = match geom {
+        Geometry::Polygon(polygon) => {
+            polygons.push(polygon);
+            return Ok(());
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            polygons.extend(multi_polygon.iter());
+            return Ok(());
+        }
+        Geometry::GeometryCollection(collection) => {
+            for member in collection {
+                collect_geometry_polygons(member, polygons)?;
+            }
+            return Ok(());
+        }
+        Geometry::Point(_) => "Point",
+        Geometry::Line(_) => "Line",
+        Geometry::LineString(_) => "LineString",
+        Geometry::MultiPoint(_) => "MultiPoint",
+        Geometry::MultiLineString(_) => "MultiLineString",
+        Geometry::Rect(_) => "Rect",
+        Geometry::Triangle(_) => "Triangle",
+    };
+
+    Err(GeoTilerError::InvalidPolygonError(
+        format!("Cannot clip a {} geometry, expected a Polygon, MultiPolygon or GeometryCollection", unsupported)
+    ))
+}
+
+/// Splits a polygon into the fragments it forms with the tiles of a grid.
+///
+/// This clips the polygon like [`clip_polygon_to_tiles`] against a grid from [`generate_grid`],
+/// but returns the fragments as standalone polygons instead of storing them in a grid. It is
+/// meant for polygons too large to be meshed at once, such as those covering more than a
+/// hemisphere (see [`polygon_exceeds_hemisphere`](crate::polygon_exceeds_hemisphere)), which can
+/// then be meshed piecewise.
+///
+/// # Arguments
+///
+/// * `polygon` - The polygon to split.
+/// * `step` - The step size in degrees of the grid, as accepted by [`generate_grid`].
+///
+/// # Returns
+///
+/// * `Ok(Vec<Polygon<f64>>)` - The non-empty, densified fragments, in grid order
+/// * `Err(GeoTilerError)` - A `GridGenerationError` if `step` is invalid, or an
+///   `InvalidPolygonError` under the same conditions as [`clip_polygon_to_tiles`]
+pub fn split_polygon_by_grid(polygon: &Polygon<f64>, step: usize) -> Result<Vec<Polygon<f64>>, GeoTilerError> {
+    let tiles = grid_iter(step)?;
+    validate_clip_polygon(polygon)?;
+
+    let mut fragments: Vec<Polygon<f64>> = Vec::new();
+    for (index, mut tile) in tiles.enumerate() {
+        clip_polygon_to_tile(&mut tile, index, polygon, DensifyDistance::Degrees(DEFAULT_MAX_DISTANCE_BETWEEN_POINTS));
+        fragments.append(&mut tile.polygons);
+    }
+
+    Ok(fragments)
+}
+
+/// Measures how much area a polygon gains or loses when clipped to a grid.
+///
+/// This is a regression guard for the clipping pipeline: the polygon is clipped to a grid from
+/// [`generate_grid`] with [`clip_polygon_to_tiles`], its fragments are clamped to their tiles
+/// with [`clamp_polygons`], and the sum of the fragment areas is compared with the area of the
+/// polygon. Both areas are planar areas in square degrees (see [`planar_polygon_area`]), so the
+/// comparison is not affected by the curvature of the sphere.
+///
+/// Densification only inserts points along existing edges and clamping only moves vertices lying
+/// within rounding distance outside their tile, so neither changes the area noticeably. Most of
+/// the error comes from the boolean intersection, which snaps coordinates to a fixed-precision
+/// lattice: for a valid polygon it is typically between 1e-10 and 1e-7, growing with the number
+/// of fragments, i.e. with finer grids and larger polygons. An error above 1e-5 points to
+/// fragments being lost, duplicated or distorted. Self-intersecting polygons have an ill-defined
+/// shoelace area and may report a large error.
+///
+/// # Arguments
+///
+/// * `polygon` - The polygon to clip, with coordinates in decimal degrees.
+/// * `step` - The step size in degrees of the grid, as accepted by [`generate_grid`].
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The relative area error `|fragments area - polygon area| / polygon area`
+/// * `Err(GeoTilerError)` - A `GridGenerationError` if `step` is invalid, or an
+///   `InvalidPolygonError` under the same conditions as [`clip_polygon_to_tiles`]
+pub fn clip_roundtrip_area_error(polygon: &Polygon<f64>, step: usize) -> Result<f64, GeoTilerError> {
+    let mut grid: Vec<Tile> = generate_grid(step)?;
+    clip_polygon_to_tiles(&mut grid, polygon)?;
+    clamp_polygons(&mut grid);
+
+    let fragments_area: f64 = grid.iter()
+        .flat_map(|tile| &tile.polygons)
+        .map(planar_polygon_area)
+        .sum();
+    let polygon_area: f64 = planar_polygon_area(polygon);
+
+    Ok((fragments_area - polygon_area).abs() / polygon_area)
+}
+
+/// Clips a polygon to a subset of the tiles of a grid.
+///
+/// This behaves like [`clip_polygon_to_tiles`] but only intersects the polygon with the tiles at
+/// the given indices, which is much faster when clipping many small features. The indices are
+/// typically obtained with [`tile_indices_in_bbox`] from the polygon's bounding box; as long as
+/// they include every tile the polygon overlaps, the fragments are identical to those of a
+/// full-grid clip.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles of the grid. The `polygons` vector of each selected tile will be
+///   updated with any intersection fragments.
+/// * `indices` - Indices into `grid` of the tiles to clip against.
+/// * `polygon` - The polygon to be clipped against the selected tiles.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` if an index is out of range, in which case no
+/// tile is modified, and any error returned by [`clip_polygon_to_tiles`].
+pub fn clip_polygon_to_subgrid(grid: &mut [Tile], indices: &[usize], polygon: &Polygon<f64>) -> Result<(), GeoTilerError> {
+    validate_clip_polygon(polygon)?;
+
+    if let Some(index) = indices.iter().find(|&&index| index >= grid.len()) {
+        return Err(GeoTilerError::GridGenerationError(
+            format!("Tile index {} is out of range for a grid of {} tiles", index, grid.len())
+        ));
+    }
+
+    for &index in indices {
+        clip_polygon_to_tile(
+            &mut grid[index],
+            index,
+            polygon,
+            DensifyDistance::Degrees(DEFAULT_MAX_DISTANCE_BETWEEN_POINTS)
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks that a polygon has enough vertices and valid coordinates to be clipped.
+fn validate_clip_polygon(polygon: &Polygon<f64>) -> Result<(), GeoTilerError> {
+    let vertex_count: usize = polygon.exterior().coords().count();
+    if vertex_count < 4 {  
+        return Err(GeoTilerError::InvalidPolygonError(
+            format!("Polygon must have at least 3 vertices, found {}", vertex_count.saturating_sub(1))
+        ));
+    }
+
+    validate_polygon_coordinates(polygon)?;
+
+    if has_zero_area(polygon) {
+        return Err(GeoTilerError::InvalidPolygonError("Polygon has zero area".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Intersects a polygon with a single tile, stores the densified fragments in the tile, tagged
+/// with the tile's index in its grid, and returns the number of fragments added.
+///
+/// Fragments are oriented with a counter-clockwise exterior and clockwise interiors, whatever
+/// the winding of the input polygon, so that meshing them is deterministic.
+///
+/// When the polygon only touches the tile along its boundary, the intersection can contain
+/// degenerate rings with fewer than 4 coordinates, which could not be meshed later. A fragment
+/// whose exterior is degenerate is skipped and degenerate interiors are dropped, both logged at
+/// debug level, so that only meshable fragments are stored.
+fn clip_polygon_to_tile(tile: &mut Tile, tile_index: usize, polygon: &Polygon<f64>, max_distance: DensifyDistance) -> usize {
+    let resulting_polygons: MultiPolygon<f64> = tile.vertices.intersection(polygon);
+    let mut fragment_count: usize = 0;
+
+    for rp in resulting_polygons {
+        let mut rp: Polygon<f64> = rp.orient(Direction::Default);
+        densify_edges_with_distance(&mut rp, max_distance);
+
+        let exterior_len: usize = rp.exterior().0.len();
+        if exterior_len < 4 {
+            log::debug!(
+                "Skipping degenerate fragment in tile {} with {} exterior coordinate(s)",
+                tile_index, exterior_len
+            );
+            continue;
+        }
+
+        let (exterior, mut interiors) = rp.into_inner();
+        let interior_count: usize = interiors.len();
+        interiors.retain(|interior| interior.0.len() >= 4);
+        if interiors.len() < interior_count {
+            log::debug!(
+                "Dropped {} degenerate interior ring(s) from a fragment in tile {}",
+                interior_count - interiors.len(), tile_index
+            );
+        }
+
+        tile.polygons.push(Polygon::new(exterior, interiors));
+        tile.fragment_tile_ids.push(tile_index);
+        fragment_count += 1;
+    }
+
+    fragment_count
+}
+
+/// Clips a polygon to a grid of tiles and reports whether it intersected any tile.
+///
+/// This behaves like [`clip_polygon_to_tiles`], but lets the caller detect polygons lying
+/// entirely outside the grid's coverage. That cannot happen with a full grid from
+/// [`generate_grid`], but is common with custom sub-grids, where such a polygon would otherwise
+/// silently produce no fragments.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles to clip against. Each tile's `polygons` vector will be updated with any
+///   intersection fragments.
+/// * `polygon` - The polygon to be clipped against the tile grid.
+///
+/// # Returns
+///
+/// * `Ok(true)` - If at least one fragment was added to the grid
+/// * `Ok(false)` - If the polygon intersected no tile and the grid was left unchanged
+/// * `Err(GeoTilerError)` - Under the same conditions as [`clip_polygon_to_tiles`]
+pub fn clip_polygon_to_tiles_reporting(grid: &mut [Tile], polygon: &Polygon<f64>) -> Result<bool, GeoTilerError> {
+    validate_clip_polygon(polygon)?;
+
+    let mut fragment_count: usize = 0;
+    for (index, tile) in grid.iter_mut().enumerate() {
+        fragment_count += clip_polygon_to_tile(tile, index, polygon, DensifyDistance::Degrees(DEFAULT_MAX_DISTANCE_BETWEEN_POINTS));
+    }
+
+    Ok(fragment_count > 0)
+}
+
+/// Clips a polygon to a grid of tiles and returns the part of the polygon that no tile covers.
+///
+/// This behaves like [`clip_polygon_to_tiles`], but is meant for partial grids, such as a
+/// handful of tiles built with [`Tile::new`] around a region of interest, where part of the
+/// polygon may fall outside every tile and would otherwise be lost silently. The remainder is the
+/// difference between the polygon and the tiles that received a fragment, so it is empty for a
+/// polygon fully inside the grid and for any polygon clipped to a full grid from [`generate_grid`].
+///
+/// # Arguments
+///
+/// * `grid` - The tiles to clip against. Each tile's `polygons` vector will be updated with any
+///   intersection fragments.
+/// * `polygon` - The polygon to be clipped against the tile grid.
+///
+/// # Returns
+///
+/// * `Result<MultiPolygon<f64>, GeoTilerError>` - The portion of the polygon lying outside all
+///   tiles, oriented with counter-clockwise exteriors. It is not densified, unlike the fragments.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`clip_polygon_to_tiles`], leaving the grid
+/// unchanged.
+pub fn clip_polygon_tracking_remainder(grid: &mut [Tile], polygon: &Polygon<f64>) -> Result<MultiPolygon<f64>, GeoTilerError> {
+    validate_clip_polygon(polygon)?;
+
+    let mut remainder: MultiPolygon<f64> = MultiPolygon::new(vec![polygon.clone()]);
+    for (index, tile) in grid.iter_mut().enumerate() {
+        let fragment_count: usize = clip_polygon_to_tile(tile, index, polygon, DensifyDistance::Degrees(DEFAULT_MAX_DISTANCE_BETWEEN_POINTS));
+
+        // tiles without fragments do not overlap the polygon, so they cannot shrink the remainder
+        if fragment_count > 0 {
+            remainder = remainder.difference(&tile.vertices);
+        }
+    }
+
+    Ok(remainder.orient(Direction::Default))
+}
+
+/// Clips a polygon to a grid of tiles after verifying that the grid is regular.
+///
+/// [`clip_polygon_to_tiles`] assumes the grid came from [`generate_grid`]. When resuming from
+/// tiles loaded from disk, this function first checks the grid with [`infer_grid_step`] so that
+/// fragments are never clipped into overlapping or mismatched tiles.
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles. Each tile's `polygons` vector will be
+///   updated with any intersection fragments.
+/// * `polygon` - The polygon to be clipped against the tile grid.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` if the grid is empty or irregular, and any error
+/// returned by [`clip_polygon_to_tiles`].
+pub fn clip_polygon_to_tiles_checked(grid: &mut Vec<Tile>, polygon: &Polygon<f64>) -> Result<(), GeoTilerError> {
+    if infer_grid_step(grid).is_none() {
+        return Err(GeoTilerError::GridGenerationError(
+            "Grid is empty or irregular: tiles must be equally sized, aligned and non-overlapping".to_string()
+        ));
+    }
+
+    clip_polygon_to_tiles(grid, polygon)
+}
+
+/// Merges the fragments of one grid into another grid with the same layout.
+///
+/// Every fragment stored in a tile of `source` is appended to the tile at the same index in
+/// `target`. This is used to combine grids that were clipped independently, for example by
+/// different threads.
+///
+/// # Arguments
+///
+/// * `target` - The grid receiving the fragments.
+/// * `source` - The grid whose fragments are appended to `target`.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` if the grids have a different number of tiles
+/// or if tiles at the same index have different boundaries.
+pub fn merge_grids(target: &mut [Tile], source: &[Tile]) -> Result<(), GeoTilerError> {
+    if target.len() != source.len() {
+        return Err(GeoTilerError::GridGenerationError(
+            format!("Cannot merge grids of different sizes: {} and {} tiles", target.len(), source.len())
+        ));
+    }
+
+    for (index, (target_tile, source_tile)) in target.iter().zip(source).enumerate() {
+        if target_tile.vertices != source_tile.vertices {
+            return Err(GeoTilerError::GridGenerationError(
+                format!("Cannot merge grids with different layouts: tile {} boundaries differ", index)
+            ));
+        }
+    }
+
+    for (target_tile, source_tile) in target.iter_mut().zip(source) {
+        target_tile.polygons.extend(source_tile.polygons.iter().cloned());
+        target_tile.fragment_tile_ids.extend(source_tile.fragment_tile_ids.iter().copied());
+    }
+
+    Ok(())
+}
+
+/// Clips many polygons to a grid of tiles concurrently.
+///
+/// The features are split into contiguous chunks, one per available thread. Each worker clips
+/// its chunk into its own empty copy of `grid_template`, and the resulting grids are merged
+/// back in chunk order with [`merge_grids`], so every tile ends up with the same fragments,
+/// in the same order, as clipping the features one after the other with
+/// [`clip_polygon_to_tiles`].
+///
+/// Only available with the `parallel` feature.
+///
+/// # Arguments
+///
+/// * `grid_template` - The grid layout to clip against. Fragments already stored in the
+///   template are kept in the returned grid.
+/// * `features` - The polygons to clip.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Tile>)` - A copy of the template holding the fragments of every feature.
+/// * `Err(GeoTilerError)` - The first error encountered while clipping a feature.
+#[cfg(feature = "parallel")]
+pub fn clip_features_parallel(grid_template: &[Tile], features: &[Polygon<f64>]) -> Result<Vec<Tile>, GeoTilerError> {
+    let mut merged: Vec<Tile> = grid_template.to_vec();
+    if features.is_empty() {
+        return Ok(merged);
+    }
+
+    let worker_count: usize = thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .min(features.len());
+    let chunk_size: usize = features.len().div_ceil(worker_count);
+
+    let worker_grids: Vec<Result<Vec<Tile>, GeoTilerError>> = thread::scope(|scope| {
+        let workers: Vec<thread::ScopedJoinHandle<Result<Vec<Tile>, GeoTilerError>>> = features
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || {
+                let mut grid: Vec<Tile> = grid_template
+                    .iter()
+                    .map(|tile| Tile::new(tile.vertices.clone()))
+                    .collect();
+
+                for feature in chunk {
+                    clip_polygon_to_tiles(&mut grid, feature)?;
+                }
+
+                Ok(grid)
+            }))
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("Clipping worker thread panicked"))
+            .collect()
+    });
+
+    for grid in worker_grids {
+        merge_grids(&mut merged, &grid?)?;
+    }
+
+    Ok(merged)
+}
+
+/// Removes the polygon fragments of every tile in a grid while keeping the tile boundaries.
+///
+/// This allows a grid to be reused for several independent datasets without regenerating it.
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles whose `polygons` vectors will be emptied.
+pub fn clear_grid(grid: &mut Vec<Tile>) {
+    for tile in grid {
+        tile.clear();
+    }
+}
+
+/// Applies a coordinate transformation to every fragment vertex of a grid.
+///
+/// This lets fragments be moved to another coordinate reference system before meshing, for
+/// instance to shift them from a local datum. Every ring of every fragment is transformed, while
+/// the tile boundaries are left unchanged since they define the layout of the grid; they can be
+/// transformed separately with `geo::MapCoordsInPlace` on `tile.vertices` if needed. The meshing
+/// functions expect longitude/latitude in decimal degrees, so the closure should produce
+/// coordinates in that form.
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles whose fragments will be transformed.
+/// * `f` - The transformation applied to each (longitude, latitude) vertex.
+pub fn transform_grid_coordinates(grid: &mut Vec<Tile>, f: impl Fn(Coord<f64>) -> Coord<f64>) {
+    for tile in grid {
+        for polygon in tile.polygons.iter_mut() {
+            polygon.map_coords_in_place(&f);
+        }
+    }
+}
+
+/// Removes the fragments whose area is below a threshold from every tile of a grid.
+///
+/// This drops the slivers left by clipping polygons whose edges run close to tile boundaries.
+/// The area of each fragment is its planar area in square degrees, computed with
+/// [`planar_polygon_area`], so a threshold removes fragments covering
+/// less ground near the poles than near the equator. `fragment_tile_ids` is filtered along with
+/// `polygons` so both stay parallel.
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles whose fragments will be filtered.
+/// * `min_area` - The smallest area in square degrees a fragment must have to be kept.
+pub fn filter_fragments_by_area(grid: &mut Vec<Tile>, min_area: f64) {
+    for tile in grid {
+        let keep: Vec<bool> = tile.polygons.iter()
+            .map(|polygon| planar_polygon_area(polygon) >= min_area)
+            .collect();
+
+        let mut kept = keep.iter();
+        tile.polygons.retain(|_| *kept.next().unwrap_or(&true));
+
+        let mut kept = keep.iter();
+        tile.fragment_tile_ids.retain(|_| *kept.next().unwrap_or(&true));
+    }
+}
+
+/// Subdivides the boundary of every tile to the spacing used for fragment edges.
+///
+/// Tiles from [`generate_grid`] only store their four corners, while fragment edges lying on a
+/// tile boundary are densified when clipped. Densifying the tile boundaries with the same
+/// `max_distance` places their intermediate vertices at the same fixed multiples of the spacing as
+/// the fragment edges (see [`densify_edges`]), so meshes built from the
+/// boundaries line up with the fragment meshes. The default spacing used by
+/// [`clip_polygon_to_tiles`] is 1 degree.
+///
+/// The tiles are left unchanged if `max_distance` is not a positive finite number. Note that the
+/// densified tiles no longer have exactly four corners, which changes the output of
+/// [`Tile::boundary_linestring`].
+///
+/// # Arguments
+///
+/// * `grid` - A mutable reference to a vector of tiles whose `vertices` rings will be densified.
+/// * `max_distance` - The maximum distance in degrees between consecutive boundary vertices.
+pub fn densify_tile_boundaries(grid: &mut Vec<Tile>, max_distance: f64) {
+    for tile in grid {
+        densify_edges(&mut tile.vertices, max_distance);
+    }
+}
+
+/// Reports pairs of adjacent tiles whose fragments do not share the same vertices along
+/// their common boundary.
+///
+/// When adjacent tiles are triangulated independently, a shared edge that is subdivided
+/// differently on each side leaves T-junctions and cracks in the rendered globe. This diagnostic
+/// collects, for each pair of tiles sharing an edge, the fragment vertices lying on that edge in
+/// both tiles and compares them. Tiles on the -180° and 180° meridians are treated as adjacent.
+///
+/// # Arguments
+///
+/// * `grid` - The clipped grid of tiles to inspect.
+///
+/// # Returns
+///
+/// * `Vec<(usize, usize)>` - The indices of every mismatched pair of adjacent tiles, smaller index
+///   first, sorted in ascending order.
+pub fn check_boundary_consistency(grid: &[Tile]) -> Vec<(usize, usize)> {
+    let extents: Vec<Option<Rect<f64>>> = grid.iter()
+        .map(|tile| tile.vertices.bounding_rect())
+        .collect();
+
+    // index tiles by their bottom-left corner to find neighbours directly
+    let mut corners: HashMap<(i64, i64), usize> = HashMap::with_capacity(grid.len());
+    for (index, extent) in extents.iter().enumerate() {
+        if let Some(extent) = extent {
+            corners.insert(corner_key(extent.min().x, extent.min().y), index);
+        }
+    }
+
+    let mut mismatched: Vec<(usize, usize)> = Vec::new();
+    for (index, extent) in extents.iter().enumerate() {
+        let Some(extent) = extent else { continue };
+
+        // right neighbour, wrapping around the antimeridian
+        let right_x: f64 = if (extent.max().x - 180.0).abs() < BOUNDARY_EPSILON { -180.0 } else { extent.max().x };
+        if let Some(&neighbour) = corners.get(&corner_key(right_x, extent.min().y)) && neighbour != index {
+            let ours: Vec<f64> = boundary_vertices(&grid[index], |c| c.x, extent.max().x, |c| c.y);
+            let theirs: Vec<f64> = boundary_vertices(&grid[neighbour], |c| c.x, right_x, |c| c.y);
+            if !same_positions(&ours, &theirs) {
+                mismatched.push((index.min(neighbour), index.max(neighbour)));
+            }
+        }
+
+        // top neighbour
+        if let Some(&neighbour) = corners.get(&corner_key(extent.min().x, extent.max().y)) {
+            let ours: Vec<f64> = boundary_vertices(&grid[index], |c| c.y, extent.max().y, |c| c.x);
+            let theirs: Vec<f64> = boundary_vertices(&grid[neighbour], |c| c.y, extent.max().y, |c| c.x);
+            if !same_positions(&ours, &theirs) {
+                mismatched.push((index.min(neighbour), index.max(neighbour)));
+            }
+        }
+    }
+
+    mismatched.sort_unstable();
+    mismatched.dedup();
+    mismatched
+}
+
+/// Rounds a tile corner to a hashable key.
+fn corner_key(x: f64, y: f64) -> (i64, i64) {
+    ((x / BOUNDARY_EPSILON).round() as i64, (y / BOUNDARY_EPSILON).round() as i64)
+}
+
+/// Collects the sorted positions along a boundary line of every fragment vertex lying on it.
+///
+/// `across` selects the coordinate compared against `line`, and `along` selects the coordinate
+/// returned for vertices on the line.
+fn boundary_vertices(
+    tile: &Tile,
+    across: impl Fn(&Coord<f64>) -> f64,
+    line: f64,
+    along: impl Fn(&Coord<f64>) -> f64
+) -> Vec<f64> {
+    let mut positions: Vec<f64> = tile.polygons.iter()
+        .flat_map(|polygon| polygon.exterior().coords())
+        .filter(|coord| (across(coord) - line).abs() < BOUNDARY_EPSILON)
+        .map(along)
+        .collect();
+
+    positions.sort_unstable_by(f64::total_cmp);
+    positions.dedup_by(|a, b| (*a - *b).abs() < BOUNDARY_EPSILON);
+    positions
+}
+
+/// Compares two sorted lists of boundary positions within tolerance.
+fn same_positions(a: &[f64], b: &[f64]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).abs() < BOUNDARY_EPSILON)
+}
+
+/// Returns the tiles whose extent overlaps a longitude/latitude bounding box.
+///
+/// This is useful to render or process only the tiles within a viewport. If `min.x > max.x`,
+/// the bounding box is treated as wrapping around the antimeridian, covering longitudes from
+/// `min.x` to 180° and from -180° to `max.x`. Tiles which only touch the bounding box along an
+/// edge are not considered overlapping.
+///
+/// # Arguments
+///
+/// * `grid` - The tiles to search.
+/// * `min` - The bottom-left corner (minimum longitude and latitude) of the bounding box.
+/// * `max` - The top-right corner (maximum longitude and latitude) of the bounding box.
+///
+/// # Returns
+///
+/// * `Vec<&Tile>` - References to the overlapping tiles, in grid order.
+pub fn tiles_in_bbox(grid: &[Tile], min: Coord<f64>, max: Coord<f64>) -> Vec<&Tile> {
+    grid.iter()
+        .filter(|tile| tile_overlaps_bbox(tile, min, max))
+        .collect()
+}
+
+/// Returns the indices of the tiles whose extent overlaps a longitude/latitude bounding box.
+///
+/// This behaves like [`tiles_in_bbox`] but returns positions in the grid, which can be passed to
+/// [`clip_polygon_to_subgrid`].
+///
+/// # Arguments
+///
+/// * `grid` - The tiles to search.
+/// * `min` - The bottom-left corner (minimum longitude and latitude) of the bounding box.
+/// * `max` - The top-right corner (maximum longitude and latitude) of the bounding box.
+///
+/// # Returns
+///
+/// * `Vec<usize>` - The indices of the overlapping tiles, in ascending order.
+pub fn tile_indices_in_bbox(grid: &[Tile], min: Coord<f64>, max: Coord<f64>) -> Vec<usize> {
+    grid.iter()
+        .enumerate()
+        .filter(|(_, tile)| tile_overlaps_bbox(tile, min, max))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Finds the stored fragment closest to a point, across every tile of a grid.
+///
+/// The distance to a fragment is the great-circle distance, computed with the haversine formula,
+/// from the point to the nearest vertex of any of its rings. Since fragments are densified when
+/// clipped, this closely approximates the distance to the fragment's boundary. A point inside a
+/// fragment is not at distance zero from it, but from its nearest boundary vertex.
+///
+/// # Arguments
+///
+/// * `grid` - The clipped grid of tiles to search.
+/// * `point` - The query point as (longitude, latitude) in decimal degrees.
+///
+/// # Returns
+///
+/// * `Some((usize, &Polygon<f64>))` - The index in `grid` of the tile holding the nearest
+///   fragment, and the fragment itself. Ties are resolved in favour of the first fragment in
+///   grid order.
+/// * `None` - If the grid has no fragments or the point has non-finite coordinates
+pub fn nearest_fragment(grid: &[Tile], point: Coord<f64>) -> Option<(usize, &Polygon<f64>)> {
+    if !point.x.is_finite() || !point.y.is_finite() {
+        return None;
+    }
+
+    let mut nearest: Option<(usize, &Polygon<f64>)> = None;
+    let mut nearest_angle: f64 = f64::INFINITY;
+
+    for (index, tile) in grid.iter().enumerate() {
+        for polygon in &tile.polygons {
+            let angle: f64 = std::iter::once(polygon.exterior())
+                .chain(polygon.interiors())
+                .flat_map(|ring| ring.coords())
+                .map(|vertex| haversine_angle(&point, vertex))
+                .fold(f64::INFINITY, f64::min);
+
+            if angle < nearest_angle {
+                nearest_angle = angle;
+                nearest = Some((index, polygon));
+            }
+        }
+    }
+
+    nearest
+}
+
+/// Returns the indices of the tiles sharing an edge with a tile of a [`generate_grid`] layout.
+///
+/// Tiles are laid out column by column, from west to east, and each column runs from south to
+/// north. The left and right neighbors wrap around the antimeridian, while tiles touching a pole
+/// have no neighbor beyond it.
+///
+/// # Arguments
+///
+/// * `index` - The index of the tile in the grid.
+/// * `step` - The step size the grid was generated with.
+///
+/// # Returns
+///
+/// * `Vec<usize>` - The indices of the neighbors below, above, to the left and to the right of
+///   the tile, in that order and without duplicates. Empty if `step` is not a valid grid step or
+///   `index` is outside the grid.
+pub fn neighbor_indices(index: usize, step: usize) -> Vec<usize> {
+    if validate_grid_step(step).is_err() {
+        return Vec::new();
+    }
+
+    let rows: usize = 180 / step;
+    let columns: usize = 360 / step;
+    if index >= rows * columns {
+        return Vec::new();
+    }
+
+    let column: usize = index / rows;
+    let row: usize = index % rows;
+
+    let mut neighbors: Vec<usize> = Vec::with_capacity(4);
+    if row > 0 {
+        neighbors.push(index - 1);
+    }
+    if row + 1 < rows {
+        neighbors.push(index + 1);
+    }
+
+    // longitude wraps around, so the first and last columns are neighbors
+    for neighbor_column in [(column + columns - 1) % columns, (column + 1) % columns] {
+        let neighbor: usize = neighbor_column * rows + row;
+        if neighbor != index && !neighbors.contains(&neighbor) {
+            neighbors.push(neighbor);
+        }
+    }
+
+    neighbors
+}
+
+/// Returns true if a tile's extent overlaps the bounding box, wrapping around the antimeridian
+/// when `min.x > max.x`.
+fn tile_overlaps_bbox(tile: &Tile, min: Coord<f64>, max: Coord<f64>) -> bool {
+    let Some(extent) = tile.vertices.bounding_rect() else { return false };
+
+    let overlaps_latitude: bool = extent.min().y < max.y && extent.max().y > min.y;
+    let overlaps_longitude: bool = if min.x <= max.x {
+        extent.min().x < max.x && extent.max().x > min.x
+    } else {
+        extent.max().x > min.x || extent.min().x < max.x
+    };
+
+    overlaps_latitude && overlaps_longitude
+}
+
+/// Clamps all polygons in each tile to ensure their coordinates stay within the tile boundaries.
+///
+/// This function addresses floating-point precision errors that can occur during polygon intersection
+/// operations, which may result in polygon vertices slightly extending beyond their containing tile's
+/// boundaries. Such precision errors can prevent proper triangulation of the polygons.
+///
+/// # Arguments
+///
+/// * `tiles` - A mutable reference to a vector of tiles. Each tile's polygons will have their
+///             coordinates clamped to the tile's boundary limits.
+pub fn clamp_polygons(tiles: &mut Vec<Tile>) {
+    for tile in tiles {
 
         // clamping is required because of float math inaccuracies which prevent triangulation from working
         let tile_exterior: &LineString = tile.vertices.exterior();
         for polygon in tile.polygons.iter_mut() {
             
-            clamp_polygon(polygon, tile_exterior);
+            clamp_polygon(polygon, tile_exterior, 0.0);
+        }
+
+    }
+}
+
+/// Clamps all polygons in each tile to the tile boundaries shrunk by a margin.
+///
+/// This behaves like [`clamp_polygons`], but moves the clamp bounds `margin` degrees inside every
+/// tile edge, so that vertices lying on or beyond the tile boundary end up strictly inside the
+/// tile, which makes the triangulation of fragments more robust. Every tile is checked before any
+/// polygon is clamped, so the tiles are left unchanged when an error is returned.
+///
+/// # Arguments
+///
+/// * `tiles` - A mutable reference to a vector of tiles. Each tile's polygons will have their
+///   coordinates clamped to the shrunk boundary limits.
+/// * `margin` - The distance in degrees between the tile edges and the clamp bounds.
+///
+/// # Errors
+///
+/// Returns `GeoTilerError::GridGenerationError` if:
+/// * `margin` is negative, since expanding the bounds would let vertices leave their tile
+/// * `margin` is not a finite number
+/// * `margin` is at least half the width or height of a tile with fragments, which would leave
+///   no room inside the tile
+pub fn clamp_polygons_with_margin(tiles: &mut Vec<Tile>, margin: f64) -> Result<(), GeoTilerError> {
+    if !margin.is_finite() || margin < 0.0 {
+        return Err(GeoTilerError::GridGenerationError(
+            format!("Clamp margin must be a non-negative finite number of degrees, got {}", margin)
+        ));
+    }
+
+    for (index, tile) in tiles.iter().enumerate() {
+        if tile.polygons.is_empty() {
+            continue;
+        }
+
+        let Some(extent) = tile.vertices.bounding_rect() else { continue };
+        if 2.0 * margin >= extent.width() || 2.0 * margin >= extent.height() {
+            return Err(GeoTilerError::GridGenerationError(
+                format!(
+                    "Clamp margin {} leaves no room inside tile {} of size {} x {}",
+                    margin, index, extent.width(), extent.height()
+                )
+            ));
         }
+    }
 
+    for tile in tiles {
+        let tile_exterior: &LineString = tile.vertices.exterior();
+        for polygon in tile.polygons.iter_mut() {
+            clamp_polygon(polygon, tile_exterior, margin);
+        }
     }
+
+    Ok(())
 }
 
 /// Clamps a single polygon's coordinates to fit within the specified tile boundary.
 ///
 /// This function calculates the minimum and maximum x and y coordinates from the tile's exterior
-/// boundary, then ensures all coordinates in the polygon's exterior ring fall within these bounds
-/// using the clamp operation.
+/// boundary, then ensures all coordinates in the polygon's exterior and interior rings fall within
+/// these bounds using the clamp operation.
 ///
 /// # Arguments
 ///
 /// * `polygon` - A mutable reference to the polygon whose coordinates will be clamped.
 /// * `tile_exterior` - The exterior boundary of the tile used to determine clamping limits.
-fn clamp_polygon(polygon: &mut Polygon, tile_exterior: &LineString<f64>) {
-    polygon.exterior_mut(|exterior| {
-        let mut max_x: f64 = f64::MIN; let mut max_y: f64 = f64::MIN; 
-        let mut min_x: f64 = f64::MAX; let mut min_y: f64 = f64::MAX;
-        
-        for coord in tile_exterior {
-            max_x = max_x.max(coord.x);
-            max_y = max_y.max(coord.y);
-            min_x = min_x.min(coord.x);
-            min_y = min_y.min(coord.y);
-        }
-
-        for coord in exterior.coords_mut() {
-            coord.x = coord.x.clamp(min_x, max_x);
-            coord.y = coord.y.clamp(min_y, max_y);
+/// * `margin` - The distance in degrees by which the limits are moved inside the tile.
+fn clamp_polygon(polygon: &mut Polygon, tile_exterior: &LineString<f64>, margin: f64) {
+    let mut max_x: f64 = f64::MIN; let mut max_y: f64 = f64::MIN; 
+    let mut min_x: f64 = f64::MAX; let mut min_y: f64 = f64::MAX;
+    
+    for coord in tile_exterior {
+        max_x = max_x.max(coord.x);
+        max_y = max_y.max(coord.y);
+        min_x = min_x.min(coord.x);
+        min_y = min_y.min(coord.y);
+    }
+
+    max_x -= margin; max_y -= margin;
+    min_x += margin; min_y += margin;
+
+    let clamp_ring = |ring: &mut LineString<f64>| -> usize {
+        let mut clamped_count: usize = 0;
+        for coord in ring.coords_mut() {
+            let clamped: Coord<f64> = Coord { x: coord.x.clamp(min_x, max_x), y: coord.y.clamp(min_y, max_y) };
+            if clamped != *coord {
+                clamped_count += 1;
+            }
+
+            *coord = clamped;
+        }
+        clamped_count
+    };
+
+    let mut clamped_count: usize = 0;
+    polygon.exterior_mut(|exterior| clamped_count += clamp_ring(exterior));
+    polygon.interiors_mut(|interiors| {
+        for interior in interiors {
+            clamped_count += clamp_ring(interior);
         }
     });
-}
\ No newline at end of file
+
+    if clamped_count > 0 {
+        log::debug!(
+            "Clamped {} coordinates to tile bounds [{}, {}] x [{}, {}]",
+            clamped_count, min_x, max_x, min_y, max_y
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::{coord, polygon, Area, Contains, Validation};
+
+    fn square_tile(x: f64, y: f64, size: f64) -> Tile {
+        Tile::new(polygon![(x: x, y: y), (x: x + size, y: y), (x: x + size, y: y + size), (x: x, y: y + size)])
+    }
+
+    #[test]
+    fn test_infer_grid_step() {
+        for step in [1, 10, 45, 90] {
+            assert_eq!(infer_grid_step(&generate_grid(step).unwrap()), Some(step));
+        }
+
+        let mixed: Vec<Tile> = vec![square_tile(0.0, 0.0, 10.0), square_tile(10.0, 0.0, 5.0)];
+        assert_eq!(infer_grid_step(&mixed), None);
+
+        let misaligned: Vec<Tile> = vec![square_tile(0.0, 0.0, 10.0), square_tile(15.0, 0.0, 10.0)];
+        assert_eq!(infer_grid_step(&misaligned), None);
+
+        let overlapping: Vec<Tile> = vec![square_tile(0.0, 0.0, 10.0), square_tile(0.0, 0.0, 10.0)];
+        assert_eq!(infer_grid_step(&overlapping), None);
+
+        assert_eq!(infer_grid_step(&[]), None);
+    }
+
+    #[test]
+    fn test_clip_polygon_to_tiles_checked() {
+        let polygon: Polygon<f64> = polygon![(x: 2.0, y: 2.0), (x: 8.0, y: 2.0), (x: 8.0, y: 8.0), (x: 2.0, y: 8.0)];
+
+        let mut irregular: Vec<Tile> = vec![square_tile(0.0, 0.0, 10.0), square_tile(5.0, 0.0, 10.0)];
+        assert!(matches!(
+            clip_polygon_to_tiles_checked(&mut irregular, &polygon),
+            Err(GeoTilerError::GridGenerationError(_))
+        ));
+        assert!(irregular.iter().all(|tile| tile.polygons.is_empty()));
+
+        let mut regular: Vec<Tile> = generate_grid(10).unwrap();
+        let mut expected: Vec<Tile> = generate_grid(10).unwrap();
+        clip_polygon_to_tiles_checked(&mut regular, &polygon).unwrap();
+        clip_polygon_to_tiles(&mut expected, &polygon).unwrap();
+
+        assert_eq!(regular.iter().map(|tile| tile.polygons.len()).sum::<usize>(), 1);
+        assert!(regular.iter().zip(&expected).all(|(a, b)| a.polygons == b.polygons));
+    }
+
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_clip_features_parallel_matches_sequential() {
+        let template: Vec<Tile> = generate_grid(30).unwrap();
+        let features: Vec<Polygon<f64>> = (0..12)
+            .map(|i| {
+                let x: f64 = -170.0 + 25.0 * i as f64;
+                let y: f64 = -60.0 + 8.0 * i as f64;
+                polygon![(x: x, y: y), (x: x + 40.0, y: y), (x: x + 40.0, y: y + 35.0), (x: x, y: y + 35.0)]
+            })
+            .collect();
+
+        let mut sequential: Vec<Tile> = template.clone();
+        for feature in &features {
+            clip_polygon_to_tiles(&mut sequential, feature).unwrap();
+        }
+        let parallel: Vec<Tile> = clip_features_parallel(&template, &features).unwrap();
+
+        assert_eq!(parallel.len(), sequential.len());
+        assert!(sequential.iter().any(|tile| tile.polygons.len() > 1));
+        for (parallel_tile, sequential_tile) in parallel.iter().zip(&sequential) {
+            assert_eq!(parallel_tile.vertices, sequential_tile.vertices);
+            assert_eq!(parallel_tile.polygons, sequential_tile.polygons);
+            assert_eq!(parallel_tile.fragment_tile_ids, sequential_tile.fragment_tile_ids);
+        }
+    }
+
+
+    #[test]
+    fn test_clear_grid_keeps_extents_and_empties_fragments() {
+        let mut grid: Vec<Tile> = generate_grid(30).unwrap();
+        let extents: Vec<Polygon<f64>> = grid.iter().map(|tile| tile.vertices.clone()).collect();
+        let polygon: Polygon<f64> = polygon![(x: -100.0, y: -40.0), (x: 100.0, y: -40.0), (x: 100.0, y: 40.0), (x: -100.0, y: 40.0)];
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+        assert!(grid.iter().any(|tile| !tile.polygons.is_empty()));
+
+        clear_grid(&mut grid);
+
+        assert_eq!(grid.len(), extents.len());
+        for (tile, extent) in grid.iter().zip(&extents) {
+            assert_eq!(&tile.vertices, extent);
+            assert!(tile.polygons.is_empty());
+            assert!(tile.fragment_tile_ids.is_empty());
+        }
+    }
+
+
+    #[test]
+    fn test_check_boundary_consistency_reports_mismatched_subdivision() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        let left: usize = 9 * 9 + 4;
+        let right: usize = 10 * 9 + 4;
+        grid[right].polygons.push(polygon![(x: 20.0, y: -5.0), (x: 30.0, y: -5.0), (x: 30.0, y: 5.0), (x: 20.0, y: 5.0)]);
+
+        grid[left].polygons.push(polygon![(x: 10.0, y: -5.0), (x: 20.0, y: -5.0), (x: 20.0, y: 5.0), (x: 10.0, y: 5.0)]);
+        assert!(check_boundary_consistency(&grid).is_empty());
+
+        grid[left].polygons[0] = polygon![(x: 10.0, y: -5.0), (x: 20.0, y: -5.0), (x: 20.0, y: 0.0), (x: 20.0, y: 5.0), (x: 10.0, y: 5.0)];
+        assert_eq!(check_boundary_consistency(&grid), vec![(left, right)]);
+    }
+
+
+    #[test]
+    fn test_tiles_in_bbox() {
+        let grid: Vec<Tile> = generate_grid(20).unwrap();
+        let extent = |tile: &Tile| -> (f64, f64) {
+            let bottom_left: Coord<f64> = tile.vertices.exterior().0[0];
+            (bottom_left.x, bottom_left.y)
+        };
+
+        // 5..35 longitude and -5..5 latitude overlaps columns 0..20 and 20..40, rows -10..10
+        let tiles: Vec<&Tile> = tiles_in_bbox(&grid, coord! {x: 5.0, y: -5.0}, coord! {x: 35.0, y: 5.0});
+        let extents: Vec<(f64, f64)> = tiles.into_iter().map(extent).collect();
+        assert_eq!(extents, vec![(0.0, -10.0), (20.0, -10.0)]);
+
+        // 170..-170 wraps across the antimeridian
+        let tiles: Vec<&Tile> = tiles_in_bbox(&grid, coord! {x: 170.0, y: 15.0}, coord! {x: -170.0, y: 25.0});
+        let extents: Vec<(f64, f64)> = tiles.into_iter().map(extent).collect();
+        assert_eq!(extents, vec![(-180.0, 10.0), (160.0, 10.0)]);
+    }
+
+
+    #[test]
+    fn test_tile_new_has_no_fragments() {
+        let vertices: Polygon<f64> = polygon![(x: -180.0, y: -90.0), (x: -160.0, y: -90.0), (x: -160.0, y: -70.0), (x: -180.0, y: -70.0)];
+        let tile: Tile = Tile::new(vertices.clone());
+
+        assert_eq!(tile.vertices, vertices);
+        assert!(tile.polygons.is_empty());
+        assert!(tile.fragment_tile_ids.is_empty());
+
+        let default: Tile = Tile::default();
+        assert!(default.vertices.exterior().0.is_empty());
+        assert!(default.polygons.is_empty());
+    }
+
+
+    #[test]
+    fn test_grid_iter_matches_generate_grid() {
+        let grid: Vec<Tile> = generate_grid(90).unwrap();
+        let lazy: Vec<Tile> = grid_iter(90).unwrap().collect();
+
+        assert_eq!(lazy.len(), 8);
+        assert_eq!(lazy.len(), grid.len());
+        for (lazy_tile, tile) in lazy.iter().zip(&grid) {
+            assert_eq!(lazy_tile.vertices, tile.vertices);
+            assert!(lazy_tile.polygons.is_empty());
+        }
+
+        assert!(grid_iter(7).is_err());
+    }
+
+
+    #[test]
+    fn test_fragments_convex_hull_contains_every_fragment() {
+        let mut tile: Tile = square_tile(0.0, 0.0, 20.0);
+        assert!(tile.fragments_convex_hull().is_none());
+
+        let first: Polygon<f64> = polygon![(x: 1.0, y: 1.0), (x: 4.0, y: 1.0), (x: 4.0, y: 4.0), (x: 1.0, y: 4.0)];
+        let second: Polygon<f64> = polygon![(x: 12.0, y: 14.0), (x: 18.0, y: 14.0), (x: 18.0, y: 19.0), (x: 12.0, y: 19.0)];
+        tile.polygons.push(first.clone());
+        tile.polygons.push(second.clone());
+
+        let hull: Polygon<f64> = tile.fragments_convex_hull().unwrap();
+        assert!(hull.contains(&first));
+        assert!(hull.contains(&second));
+        assert!(planar_polygon_area(&hull) < planar_polygon_area(&tile.vertices));
+    }
+
+
+    #[test]
+    fn test_clip_polygon_to_subgrid_matches_full_clip() {
+        let polygon: Polygon<f64> = polygon![(x: -35.0, y: 5.0), (x: 12.0, y: -8.0), (x: 25.0, y: 33.0), (x: -10.0, y: 41.0)];
+        let rect: Rect<f64> = polygon.bounding_rect().unwrap();
+
+        let mut full: Vec<Tile> = generate_grid(20).unwrap();
+        clip_polygon_to_tiles(&mut full, &polygon).unwrap();
+
+        let mut partial: Vec<Tile> = generate_grid(20).unwrap();
+        let indices: Vec<usize> = tile_indices_in_bbox(&partial, rect.min(), rect.max());
+        assert!(indices.len() < partial.len());
+        clip_polygon_to_subgrid(&mut partial, &indices, &polygon).unwrap();
+
+        assert!(full.iter().filter(|tile| !tile.polygons.is_empty()).count() > 1);
+        for (partial_tile, full_tile) in partial.iter().zip(&full) {
+            assert_eq!(partial_tile.polygons, full_tile.polygons);
+            assert_eq!(partial_tile.fragment_tile_ids, full_tile.fragment_tile_ids);
+        }
+    }
+
+
+    #[test]
+    fn test_boundary_linestring_has_four_corners() {
+        let grid: Vec<Tile> = generate_grid(20).unwrap();
+
+        for tile in &grid {
+            assert!(tile.vertices.is_valid());
+            let boundary: LineString<f64> = tile.boundary_linestring();
+            assert_eq!(boundary.0.len(), 4);
+            assert!(!boundary.is_closed());
+        }
+
+        let (x, y): (f64, f64) = (-180.0, -90.0);
+        assert_eq!(
+            grid[0].boundary_linestring(),
+            LineString::from(vec![(x, y), (x + 20.0, y), (x + 20.0, y + 20.0), (x, y + 20.0)])
+        );
+    }
+
+
+    #[test]
+    fn test_tile_dimensions() {
+        for step in [10, 20, 45, 90] {
+            let grid: Vec<Tile> = generate_grid(step).unwrap();
+            let step: f64 = step as f64;
+
+            for tile in &grid {
+                // [bl, br, tr, tl, bl]
+                let coords: &Vec<Coord<f64>> = &tile.vertices.exterior().0;
+                assert_eq!(coords.len(), 5);
+                assert_eq!(coords[0], coords[4]);
+                assert_eq!(coords[1].x - coords[0].x, step);
+                assert_eq!(coords[1].y, coords[0].y);
+                assert_eq!(coords[2].x, coords[1].x);
+                assert_eq!(coords[2].y - coords[1].y, step);
+                assert_eq!(coords[3].x, coords[0].x);
+                assert_eq!(coords[3].y, coords[2].y);
+
+                assert!(tile.vertices.exterior().is_closed());
+                assert!(tile.vertices.is_valid());
+                assert_eq!(tile.vertices.signed_area(), step * step);
+            }
+        }
+    }
+
+
+    #[test]
+    fn test_clip_polygon_to_tiles_reporting_signals_no_intersection() {
+        let mut grid: Vec<Tile> = vec![
+            square_tile(0.0, 0.0, 10.0),
+            square_tile(0.0, 10.0, 10.0),
+            square_tile(10.0, 0.0, 10.0),
+            square_tile(10.0, 10.0, 10.0),
+        ];
+
+        let far_away: Polygon<f64> = polygon![(x: 100.0, y: 50.0), (x: 110.0, y: 50.0), (x: 110.0, y: 60.0), (x: 100.0, y: 60.0)];
+        assert!(!clip_polygon_to_tiles_reporting(&mut grid, &far_away).unwrap());
+        assert!(grid.iter().all(|tile| tile.polygons.is_empty()));
+
+        let inside: Polygon<f64> = polygon![(x: 5.0, y: 5.0), (x: 15.0, y: 5.0), (x: 15.0, y: 15.0), (x: 5.0, y: 15.0)];
+        assert!(clip_polygon_to_tiles_reporting(&mut grid, &inside).unwrap());
+        assert!(grid.iter().all(|tile| tile.polygons.len() == 1));
+    }
+
+
+    #[test]
+    fn test_tile_serde_round_trip() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        let polygon: Polygon<f64> = Polygon::new(
+            LineString::from(vec![(1.25, 1.5), (18.0, 2.0), (17.5, 19.0), (2.0, 18.75), (1.25, 1.5)]),
+            vec![LineString::from(vec![(5.0, 5.0), (9.0, 5.0), (9.0, 9.0), (5.0, 5.0)])],
+        );
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+        let tile: &Tile = grid.iter().find(|tile| !tile.polygons.is_empty()).unwrap();
+
+        let json: String = serde_json::to_string(tile).unwrap();
+        let read: Tile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(read.vertices, tile.vertices);
+        assert_eq!(read.polygons, tile.polygons);
+        assert_eq!(read.polygons[0].interiors().len(), 1);
+        assert_eq!(read.fragment_tile_ids, tile.fragment_tile_ids);
+
+        assert!(serde_json::from_str::<Tile>(r#"{"vertices": [], "polygons": [], "fragment_tile_ids": []}"#).is_err());
+    }
+
+
+    #[test]
+    fn test_generate_grid_filtered_keeps_tiles_in_bbox() {
+        let in_bbox = |tile: &Tile| {
+            let rect: Rect<f64> = tile.vertices.bounding_rect().unwrap();
+            rect.min().x >= -20.0 && rect.max().x <= 40.0 && rect.min().y >= 0.0 && rect.max().y <= 60.0
+        };
+        let filtered: Vec<Tile> = generate_grid_filtered(20, in_bbox).unwrap();
+
+        let corners: Vec<(f64, f64)> = filtered.iter()
+            .map(|tile| (tile.vertices.exterior().0[0].x, tile.vertices.exterior().0[0].y))
+            .collect();
+        assert_eq!(corners, vec![
+            (-20.0, 10.0), (-20.0, 30.0),
+            (0.0, 10.0), (0.0, 30.0),
+            (20.0, 10.0), (20.0, 30.0),
+        ]);
+        assert!(generate_grid_filtered(7, |_| true).is_err());
+    }
+
+
+    #[test]
+    fn test_clipped_fragment_keeps_hole() {
+        let polygon: Polygon<f64> = Polygon::new(
+            LineString::from(vec![(0.0, 12.0), (30.0, 12.0), (30.0, 28.0), (0.0, 28.0), (0.0, 12.0)]),
+            vec![LineString::from(vec![(5.0, 15.0), (15.0, 15.0), (15.0, 25.0), (5.0, 25.0), (5.0, 15.0)])],
+        );
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        let with_hole: usize = 9 * 9 + 5;
+        let without_hole: usize = 10 * 9 + 5;
+        assert_eq!(grid[with_hole].polygons.len(), 1);
+        assert_eq!(grid[without_hole].polygons.len(), 1);
+        assert!(grid[without_hole].polygons[0].interiors().is_empty());
+
+        let fragment: &Polygon<f64> = &grid[with_hole].polygons[0];
+        assert_eq!(fragment.interiors().len(), 1);
+        let hole: Rect<f64> = fragment.interiors()[0].bounding_rect().unwrap();
+        assert_eq!((hole.min(), hole.max()), (coord! {x: 5.0, y: 15.0}, coord! {x: 15.0, y: 25.0}));
+        assert!((fragment.unsigned_area() - (20.0 * 16.0 - 100.0)).abs() < 1e-9);
+
+        // the mesh keeps the hole as a constrained ring and places no vertex inside it
+        let mesh: crate::PolygonMeshData = crate::generate_polygon_feature_mesh(fragment).unwrap();
+        let ring_points: usize = fragment.exterior().0.len() + fragment.interiors()[0].0.len();
+        assert_eq!(mesh.boundary_indices.len(), ring_points);
+        for &vertex in &mesh.vertices {
+            let (lon, lat) = crate::cartesian_to_ll(vertex).unwrap();
+            assert!(!(lon > 5.0 + 1e-6 && lon < 15.0 - 1e-6 && lat > 15.0 + 1e-6 && lat < 25.0 - 1e-6));
+        }
+    }
+
+
+    #[test]
+    fn test_neighbor_indices() {
+        // a 20° grid has 9 rows per column and 18 columns
+        assert_eq!(neighbor_indices(5 * 9 + 4, 20), vec![48, 50, 40, 58]);
+        assert_eq!(neighbor_indices(4, 20), vec![3, 5, 17 * 9 + 4, 13]);
+        assert_eq!(neighbor_indices(17 * 9 + 4, 20), vec![156, 158, 16 * 9 + 4, 4]);
+        assert_eq!(neighbor_indices(5 * 9, 20), vec![46, 36, 54]);
+        assert_eq!(neighbor_indices(5 * 9 + 8, 20), vec![52, 44, 62]);
+
+        // the neighbors really share an edge with the tile
+        let grid: Vec<Tile> = generate_grid(20).unwrap();
+        for neighbor in neighbor_indices(49, 20) {
+            let shared: usize = grid[49].boundary_linestring().0.iter()
+                .filter(|corner| grid[neighbor].vertices.exterior().0.contains(corner))
+                .count();
+            assert_eq!(shared, 2);
+        }
+
+        assert!(neighbor_indices(162, 20).is_empty());
+        assert!(neighbor_indices(0, 7).is_empty());
+    }
+
+    #[test]
+    fn test_grid_reaches_poles_and_antimeridian_for_every_valid_step() {
+        for step in (1..=180).filter(|s| 180 % s == 0) {
+            let grid: Vec<Tile> = generate_grid(step).unwrap();
+            assert_eq!(grid.len(), (360 / step) * (180 / step), "step {}", step);
+
+            let coords = || grid.iter().flat_map(|tile| tile.vertices.exterior().coords());
+            let max_y: f64 = coords().map(|coord| coord.y).fold(f64::MIN, f64::max);
+            let min_y: f64 = coords().map(|coord| coord.y).fold(f64::MAX, f64::min);
+            let max_x: f64 = coords().map(|coord| coord.x).fold(f64::MIN, f64::max);
+            let min_x: f64 = coords().map(|coord| coord.x).fold(f64::MAX, f64::min);
+
+            assert_eq!(max_y, 90.0, "step {}", step);
+            assert_eq!(min_y, -90.0, "step {}", step);
+            assert_eq!(max_x, 180.0, "step {}", step);
+            assert_eq!(min_x, -180.0, "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_clip_geometry_to_tiles_matches_polygon_clipping() {
+        let first: Polygon<f64> = polygon![(x: 2.0, y: 2.0), (x: 15.0, y: 2.0), (x: 15.0, y: 15.0), (x: 2.0, y: 15.0)];
+        let second: Polygon<f64> = polygon![(x: -50.0, y: 30.0), (x: -30.0, y: 30.0), (x: -30.0, y: 50.0), (x: -50.0, y: 50.0)];
+
+        let mut expected: Vec<Tile> = generate_grid(20).unwrap();
+        clip_polygon_to_tiles(&mut expected, &first).unwrap();
+
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        clip_geometry_to_tiles(&mut grid, &Geometry::Polygon(first.clone())).unwrap();
+        assert_eq!(grid, expected);
+
+        clip_polygon_to_tiles(&mut expected, &second).unwrap();
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        clip_geometry_to_tiles(&mut grid, &Geometry::MultiPolygon(MultiPolygon::new(vec![first.clone(), second.clone()]))).unwrap();
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_clip_geometry_to_tiles_rejects_unsupported_geometries() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        let point: Geometry<f64> = Geometry::Point(geo::Point::new(5.0, 5.0));
+        assert!(matches!(clip_geometry_to_tiles(&mut grid, &point), Err(GeoTilerError::InvalidPolygonError(_))));
+
+        // a bad member of a collection leaves the grid untouched
+        let square: Polygon<f64> = polygon![(x: 2.0, y: 2.0), (x: 8.0, y: 2.0), (x: 8.0, y: 8.0), (x: 2.0, y: 8.0)];
+        let collection: Geometry<f64> = Geometry::GeometryCollection(geo::GeometryCollection::new_from(vec![Geometry::Polygon(square), point]));
+        assert!(clip_geometry_to_tiles(&mut grid, &collection).is_err());
+        assert!(grid.iter().all(|tile| tile.polygons.is_empty()));
+    }
+
+    #[test]
+    fn test_grid_with_caps_replaces_polar_slivers() {
+        let grid: Vec<Tile> = generate_grid_with_caps(5, 85).unwrap();
+        let (south, north): (&Tile, &Tile) = (&grid[0], &grid[grid.len() - 1]);
+
+        for (cap, bottom, top) in [(south, -90.0, -85.0), (north, 85.0, 90.0)] {
+            let extent: Rect<f64> = cap.vertices.bounding_rect().unwrap();
+            assert_eq!((extent.min().x, extent.max().x), (-180.0, 180.0));
+            assert_eq!((extent.min().y, extent.max().y), (bottom, top));
+        }
+
+        // no other tile reaches beyond the cap latitudes
+        for tile in &grid[1..grid.len() - 1] {
+            assert!(tile.vertices.exterior().coords().all(|coord| coord.y.abs() <= 85.0));
+        }
+        assert_eq!(grid.len(), 2 + (360 / 5) * (170 / 5));
+
+        assert!(generate_grid_with_caps(5, 87).is_err());
+        assert!(generate_grid_with_caps(5, 90).is_err());
+    }
+
+    #[test]
+    fn test_clipped_fragments_are_counter_clockwise_with_clockwise_holes() {
+        use geo::Winding;
+
+        // clockwise exterior with a counter-clockwise hole, both the opposite of the normalized orientation
+        let polygon: Polygon<f64> = polygon!(
+            exterior: [(x: 2.0, y: -8.0), (x: 2.0, y: 8.0), (x: 18.0, y: 8.0), (x: 18.0, y: -8.0)],
+            interiors: [[(x: 5.0, y: -3.0), (x: 9.0, y: -3.0), (x: 9.0, y: 3.0), (x: 5.0, y: 3.0)]],
+        );
+        assert!(polygon.exterior().is_cw());
+
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        let fragments: Vec<&Polygon<f64>> = grid.iter().flat_map(|tile| &tile.polygons).collect();
+        assert_eq!(fragments.len(), 1);
+        assert!(fragments[0].exterior().is_ccw());
+        assert_eq!(fragments[0].interiors().len(), 1);
+        assert!(fragments[0].interiors()[0].is_cw());
+    }
+
+    #[test]
+    fn test_densify_tile_boundaries_matches_fragment_spacing() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        densify_tile_boundaries(&mut grid, 1.0);
+
+        let tile: &Tile = &grid[0];
+        // 20 one-degree segments on each of the four sides, plus the closing vertex
+        assert_eq!(tile.vertices.exterior().0.len(), 4 * 20 + 1);
+        for line in tile.vertices.exterior().lines() {
+            assert!(((line.end.x - line.start.x).abs() + (line.end.y - line.start.y).abs() - 1.0).abs() < 1e-9);
+        }
+        assert!(tile.vertices.exterior().coords().all(|coord| coord.x.fract() == 0.0 && coord.y.fract() == 0.0));
+
+        // a fragment edge lying on the tile boundary gets the same vertices
+        let mut clipped: Vec<Tile> = generate_grid(20).unwrap();
+        clip_polygon_to_tiles(&mut clipped, &polygon![(x: -180.0, y: -90.0), (x: -170.0, y: -90.0), (x: -170.0, y: -85.0), (x: -180.0, y: -85.0)]).unwrap();
+        let south_edge = |ring: &LineString<f64>| -> Vec<Coord<f64>> {
+            ring.coords().filter(|coord| coord.y == -90.0 && coord.x <= -170.0).copied().collect()
+        };
+        let mut fragment_edge: Vec<Coord<f64>> = south_edge(clipped[0].polygons[0].exterior());
+        let mut tile_edge: Vec<Coord<f64>> = south_edge(tile.vertices.exterior());
+        fragment_edge.sort_by(|a, b| a.x.total_cmp(&b.x));
+        fragment_edge.dedup();
+        tile_edge.sort_by(|a, b| a.x.total_cmp(&b.x));
+        tile_edge.dedup();
+        assert_eq!(fragment_edge, tile_edge);
+
+        let mut unchanged: Vec<Tile> = generate_grid(20).unwrap();
+        densify_tile_boundaries(&mut unchanged, 0.0);
+        assert_eq!(unchanged, generate_grid(20).unwrap());
+    }
+
+    #[test]
+    fn test_polygon_sharing_an_edge_stores_no_degenerate_fragment() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        // lies in the tile from (0, -10) to (20, 10) and shares its whole west edge with the tile to the west
+        let polygon: Polygon<f64> = polygon![(x: 0.0, y: -10.0), (x: 10.0, y: -10.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+
+        let fragments: Vec<&Polygon<f64>> = grid.iter().flat_map(|tile| &tile.polygons).collect();
+        assert!(!fragments.is_empty());
+        for fragment in &fragments {
+            assert!(fragment.exterior().0.len() >= 4);
+            assert!(fragment.interiors().iter().all(|interior| interior.0.len() >= 4));
+            assert!(planar_polygon_area(fragment) > 0.0);
+        }
+        assert_eq!(grid.iter().map(|tile| tile.fragment_tile_ids.len()).sum::<usize>(), fragments.len());
+        assert!((fragments.iter().map(|fragment| planar_polygon_area(fragment)).sum::<f64>() - 200.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_clamp_polygons_with_margin_moves_boundary_vertices_inside() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        let polygon: Polygon<f64> = polygon![(x: 5.0, y: -5.0), (x: 25.0, y: -5.0), (x: 25.0, y: 5.0), (x: 5.0, y: 5.0)];
+        clip_polygon_to_tiles(&mut grid, &polygon).unwrap();
+        let index: usize = grid.iter().position(|tile| tile.vertices.exterior().0[0] == coord! {x: 0.0, y: -10.0}).unwrap();
+        assert!(grid[index].polygons[0].exterior().coords().any(|coord| coord.x == 20.0));
+
+        let margin: f64 = 0.01;
+        clamp_polygons_with_margin(&mut grid, margin).unwrap();
+
+        // the vertices on the east edge of the tile at longitude 20 are now `margin` inside it
+        let fragment: &Polygon<f64> = &grid[index].polygons[0];
+        let max_x: f64 = fragment.exterior().coords().map(|coord| coord.x).fold(f64::MIN, f64::max);
+        assert!((max_x - (20.0 - margin)).abs() < 1e-12);
+        assert!(fragment.exterior().coords().any(|coord| coord.x == 5.0));
+
+        assert!(clamp_polygons_with_margin(&mut grid, -0.01).is_err());
+        assert!(clamp_polygons_with_margin(&mut grid, 10.0).is_err());
+    }
+
+    #[test]
+    fn test_overlapping_fragments_are_detected_and_unioned() {
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        clip_polygon_to_tiles(&mut grid, &polygon![(x: 2.0, y: -8.0), (x: 12.0, y: -8.0), (x: 12.0, y: 2.0), (x: 2.0, y: 2.0)]).unwrap();
+        clip_polygon_to_tiles(&mut grid, &polygon![(x: 7.0, y: -3.0), (x: 17.0, y: -3.0), (x: 17.0, y: 7.0), (x: 7.0, y: 7.0)]).unwrap();
+        let tile: &mut Tile = grid.iter_mut().find(|tile| !tile.polygons.is_empty()).unwrap();
+        assert_eq!(tile.polygons.len(), 2);
+        assert!(tile.has_overlapping_fragments());
+
+        let area = |tile: &Tile| -> f64 { tile.polygons.iter().map(planar_polygon_area).sum() };
+        assert!((area(tile) - 200.0).abs() < 1e-6);
+
+        tile.union_fragments();
+        // the two 10 x 10 squares overlap over 5 x 5 degrees
+        assert_eq!(tile.polygons.len(), 1);
+        assert!((area(tile) - 175.0).abs() < 1e-6);
+        assert!(!tile.has_overlapping_fragments());
+        assert_eq!(tile.fragment_tile_ids.len(), 1);
+
+        // fragments sharing only an edge do not overlap
+        let mut touching: Tile = square_tile(0.0, 0.0, 20.0);
+        touching.polygons.push(polygon![(x: 0.0, y: 0.0), (x: 5.0, y: 0.0), (x: 5.0, y: 5.0), (x: 0.0, y: 5.0)]);
+        touching.polygons.push(polygon![(x: 5.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 5.0), (x: 5.0, y: 5.0)]);
+        assert!(!touching.has_overlapping_fragments());
+    }
+
+    #[test]
+    fn test_validate_grid_tessellation() {
+        for step in [1, 5, 9, 20, 45, 90, 180] {
+            validate_grid_tessellation(&generate_grid(step).unwrap(), step).unwrap();
+        }
+        validate_grid_tessellation(&generate_grid_with_caps(10, 80).unwrap(), 10).unwrap();
+
+        // a missing tile leaves a gap
+        let mut gap: Vec<Tile> = generate_grid(20).unwrap();
+        gap.remove(5);
+        assert!(matches!(validate_grid_tessellation(&gap, 20), Err(GeoTilerError::GridGenerationError(_))));
+
+        // a duplicated tile overlaps its original
+        let mut overlap: Vec<Tile> = generate_grid(20).unwrap();
+        overlap.push(overlap[3].clone());
+        let message: String = validate_grid_tessellation(&overlap, 20).unwrap_err().to_string();
+        assert!(message.contains("Tiles 3 and 162 overlap"));
+
+        // a tile off the lattice is rejected
+        let mut shifted: Vec<Tile> = generate_grid(20).unwrap();
+        shifted[0] = square_tile(-179.5, -90.0, 20.0);
+        assert!(validate_grid_tessellation(&shifted, 20).is_err());
+    }
+
+    #[test]
+    fn test_split_polygon_by_grid_returns_meshable_fragments() {
+        // Spans six 20° columns (-50..50) and three rows (-30..30) of the grid
+        let large: Polygon<f64> = polygon![(x: -50.0, y: -30.0), (x: 50.0, y: -30.0), (x: 50.0, y: 30.0), (x: -50.0, y: 30.0)];
+
+        let fragments: Vec<Polygon<f64>> = split_polygon_by_grid(&large, 20).unwrap();
+        assert_eq!(fragments.len(), 18);
+
+        let fragment_area: f64 = fragments.iter().map(|fragment| fragment.unsigned_area()).sum();
+        assert!((fragment_area - large.unsigned_area()).abs() < 1e-6);
+
+        for fragment in &fragments {
+            let mesh: crate::PolygonMeshData = crate::generate_polygon_feature_mesh(fragment).unwrap();
+            assert!(!mesh.triangles.is_empty());
+        }
+
+        assert!(matches!(split_polygon_by_grid(&large, 7), Err(GeoTilerError::GridGenerationError(_))));
+    }
+
+    #[test]
+    fn test_filter_fragments_by_area_drops_slivers() {
+        let mut grid: Vec<Tile> = vec![square_tile(0.0, 0.0, 20.0), square_tile(20.0, 0.0, 20.0)];
+        let large: Polygon<f64> = polygon![(x: 1.0, y: 1.0), (x: 11.0, y: 1.0), (x: 11.0, y: 11.0), (x: 1.0, y: 11.0)];
+        let sliver: Polygon<f64> = polygon![(x: 12.0, y: 1.0), (x: 19.0, y: 1.0), (x: 19.0, y: 1.001), (x: 12.0, y: 1.001)];
+        grid[0].polygons = vec![sliver.clone(), large.clone()];
+        grid[0].fragment_tile_ids = vec![7, 8];
+        grid[1].polygons = vec![sliver];
+        grid[1].fragment_tile_ids = vec![9];
+
+        filter_fragments_by_area(&mut grid, 0.5);
+
+        assert_eq!(grid[0].polygons, vec![large]);
+        assert_eq!(grid[0].fragment_tile_ids, vec![8]);
+        assert!(grid[1].polygons.is_empty());
+        assert!(grid[1].fragment_tile_ids.is_empty());
+    }
+
+    #[test]
+    fn test_nearest_fragment_uses_great_circle_distance() {
+        let mut grid: Vec<Tile> = vec![square_tile(20.0, 70.0, 20.0), square_tile(0.0, 60.0, 20.0)];
+        assert_eq!(nearest_fragment(&grid, coord! {x: 0.0, y: 80.0}), None);
+
+        let polar: Polygon<f64> = polygon![(x: 30.0, y: 80.0), (x: 35.0, y: 80.0), (x: 35.0, y: 85.0), (x: 30.0, y: 85.0)];
+        let southern: Polygon<f64> = polygon![(x: 0.0, y: 62.0), (x: 5.0, y: 62.0), (x: 5.0, y: 70.0), (x: 0.0, y: 70.0)];
+        grid[0].polygons.push(polar.clone());
+        grid[1].polygons.push(southern.clone());
+
+        // 30° of longitude at 80°N is only about 5° of arc, nearer than the vertex 10° south
+        assert_eq!(nearest_fragment(&grid, coord! {x: 0.0, y: 80.0}), Some((0, &polar)));
+        assert_eq!(nearest_fragment(&grid, coord! {x: 2.0, y: 60.0}), Some((1, &southern)));
+        assert_eq!(nearest_fragment(&grid, coord! {x: f64::NAN, y: 60.0}), None);
+
+        // distances wrap across the antimeridian
+        let mut grid: Vec<Tile> = vec![square_tile(160.0, -10.0, 20.0), square_tile(-180.0, -10.0, 20.0)];
+        let west: Polygon<f64> = polygon![(x: 165.0, y: 0.0), (x: 170.0, y: 0.0), (x: 170.0, y: 5.0), (x: 165.0, y: 5.0)];
+        let east: Polygon<f64> = polygon![(x: -179.0, y: 0.0), (x: -175.0, y: 0.0), (x: -175.0, y: 5.0), (x: -179.0, y: 5.0)];
+        grid[0].polygons.push(west);
+        grid[1].polygons.push(east.clone());
+        assert_eq!(nearest_fragment(&grid, coord! {x: 178.0, y: 0.0}), Some((1, &east)));
+    }
+
+    #[test]
+    fn test_transform_grid_coordinates_shifts_fragments_only() {
+        let mut grid: Vec<Tile> = vec![square_tile(0.0, -10.0, 20.0)];
+        let fragment: Polygon<f64> = Polygon::new(
+            LineString::from(vec![(2.0, -5.0), (10.0, -5.0), (10.0, 5.0), (2.0, 5.0), (2.0, -5.0)]),
+            vec![LineString::from(vec![(4.0, -1.0), (6.0, -1.0), (6.0, 1.0), (4.0, -1.0)])],
+        );
+        grid[0].polygons.push(fragment.clone());
+        let boundary: Polygon<f64> = grid[0].vertices.clone();
+
+        transform_grid_coordinates(&mut grid, |c| coord! {x: c.x + 1.5, y: c.y});
+
+        let moved: &Polygon<f64> = &grid[0].polygons[0];
+        let shifted = |ring: &LineString<f64>| -> Vec<Coord<f64>> { ring.coords().map(|c| coord! {x: c.x + 1.5, y: c.y}).collect() };
+        assert_eq!(moved.exterior().coords().copied().collect::<Vec<Coord<f64>>>(), shifted(fragment.exterior()));
+        assert_eq!(moved.interiors()[0].coords().copied().collect::<Vec<Coord<f64>>>(), shifted(&fragment.interiors()[0]));
+        assert_eq!(grid[0].vertices, boundary);
+    }
+
+    #[test]
+    fn test_antimeridian_halves_land_in_edge_columns() {
+        let step: usize = 20;
+        let rows: usize = 180 / step;
+        let last_column: usize = 360 / step - 1;
+        let mut grid: Vec<Tile> = generate_grid(step).unwrap();
+
+        // a polygon straddling ±180 split into its two RFC 7946 halves
+        let east_of_minus_180: Polygon<f64> = polygon![(x: -180.0, y: -5.0), (x: -170.0, y: -5.0), (x: -170.0, y: 5.0), (x: -180.0, y: 5.0)];
+        let west_of_plus_180: Polygon<f64> = polygon![(x: 170.0, y: -5.0), (x: 180.0, y: -5.0), (x: 180.0, y: 5.0), (x: 170.0, y: 5.0)];
+        clip_polygon_to_tiles(&mut grid, &east_of_minus_180).unwrap();
+        clip_polygon_to_tiles(&mut grid, &west_of_plus_180).unwrap();
+
+        // the equator row spans -10..10 in a 20° grid
+        let row: usize = 4;
+        let filled: Vec<usize> = grid.iter().enumerate()
+            .filter(|(_, tile)| !tile.polygons.is_empty())
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(filled, vec![row, last_column * rows + row]);
+
+        for (index, half) in [(row, &east_of_minus_180), (last_column * rows + row, &west_of_plus_180)] {
+            let fragments: &Vec<Polygon<f64>> = &grid[index].polygons;
+            assert_eq!(fragments.len(), 1);
+            assert!((fragments[0].unsigned_area() - half.unsigned_area()).abs() < 1e-9);
+        }
+
+        assert!(neighbor_indices(row, step).contains(&(last_column * rows + row)));
+    }
+
+    #[test]
+    fn test_zero_area_polygon_is_rejected_when_clipping_and_meshing() {
+        let collinear: Polygon<f64> = polygon![(x: 0.0, y: 0.0), (x: 5.0, y: 5.0), (x: 10.0, y: 10.0), (x: 0.0, y: 0.0)];
+        assert!(has_zero_area(&collinear));
+
+        let mut grid: Vec<Tile> = generate_grid(20).unwrap();
+        let expected: GeoTilerError = GeoTilerError::InvalidPolygonError("Polygon has zero area".to_string());
+        assert_eq!(clip_polygon_to_tiles(&mut grid, &collinear).unwrap_err().to_string(), expected.to_string());
+        assert!(grid.iter().all(|tile| tile.polygons.is_empty()));
+
+        assert_eq!(crate::generate_polygon_feature_mesh(&collinear).unwrap_err().to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_generate_grid_with_origin_aligns_edges_to_origin() {
+        let step: usize = 20;
+        let grid: Vec<Tile> = generate_grid_with_origin(step, 5.0, 5.0).unwrap();
+        assert_eq!(grid.len(), 19 * 10);
+
+        let aligned = |value: f64, bound: f64| -> bool {
+            value.abs() == bound || ((value - 5.0).rem_euclid(step as f64)).abs() < 1e-9
+        };
+        for tile in &grid {
+            for vertex in tile.vertices.exterior().coords() {
+                assert!(aligned(vertex.x, 180.0), "longitude {} is not aligned", vertex.x);
+                assert!(aligned(vertex.y, 90.0), "latitude {} is not aligned", vertex.y);
+            }
+        }
+
+        // the grid still covers the sphere without gaps or overlaps
+        let total_area: f64 = grid.iter().map(|tile| tile.vertices.unsigned_area()).sum();
+        assert!((total_area - 360.0 * 180.0).abs() < 1e-6);
+        assert!(grid.iter().any(|tile| tile.vertices.exterior().coords().any(|c| c.x == -180.0))
+            && grid.iter().any(|tile| tile.vertices.exterior().coords().any(|c| c.x == 180.0)));
+
+        // the origin only matters modulo the step
+        assert_eq!(generate_grid_with_origin(step, 365.0, -95.0).unwrap(), grid);
+        assert_eq!(generate_grid_with_origin(step, 0.0, -90.0).unwrap(), generate_grid(step).unwrap());
+
+        assert!(matches!(generate_grid_with_origin(step, f64::NAN, 0.0), Err(GeoTilerError::GridGenerationError(_))));
+    }
+
+    #[test]
+    fn test_clip_polygon_tracking_remainder_on_partial_grid() {
+        let mut grid: Vec<Tile> = vec![
+            square_tile(0.0, 0.0, 10.0),
+            square_tile(0.0, 10.0, 10.0),
+            square_tile(10.0, 0.0, 10.0),
+            square_tile(10.0, 10.0, 10.0),
+        ];
+        let polygon: Polygon<f64> = polygon![(x: 15.0, y: 5.0), (x: 25.0, y: 5.0), (x: 25.0, y: 15.0), (x: 15.0, y: 15.0)];
+
+        let remainder: MultiPolygon<f64> = clip_polygon_tracking_remainder(&mut grid, &polygon).unwrap();
+
+        let outside: Polygon<f64> = polygon![(x: 20.0, y: 5.0), (x: 25.0, y: 5.0), (x: 25.0, y: 15.0), (x: 20.0, y: 15.0)];
+        assert!(remainder.xor(&outside).unsigned_area() < 1e-9);
+        assert!((remainder.unsigned_area() - 50.0).abs() < 1e-9);
+
+        let fragment_area: f64 = grid.iter().flat_map(|tile| &tile.polygons).map(|fragment| fragment.unsigned_area()).sum();
+        assert!((fragment_area - 50.0).abs() < 1e-9);
+        assert!(grid[0].polygons.is_empty() && grid[1].polygons.is_empty());
+
+        let inside: Polygon<f64> = polygon![(x: 2.0, y: 2.0), (x: 18.0, y: 2.0), (x: 18.0, y: 18.0), (x: 2.0, y: 18.0)];
+        assert!(clip_polygon_tracking_remainder(&mut grid, &inside).unwrap().0.is_empty());
+        assert!(clip_polygon_tracking_remainder(&mut generate_grid(20).unwrap(), &polygon).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn test_grid_clip_matches_flat_clip_and_visits_few_tiles() {
+        let polygon: Polygon<f64> = polygon![(x: 5.0, y: -12.0), (x: 35.0, y: -8.0), (x: 32.0, y: 12.0), (x: 18.0, y: 14.0), (x: 8.0, y: 6.0)];
+
+        let mut flat: Vec<Tile> = generate_grid(10).unwrap();
+        clip_polygon_to_tiles(&mut flat, &polygon).unwrap();
+
+        let mut grid: Grid = Grid::new(10).unwrap();
+        let visited: usize = grid.clip(&polygon).unwrap();
+        // columns 0..40 and rows -20..20 under the bounding box
+        assert_eq!(visited, 4 * 4);
+        assert!(visited * 20 < flat.len());
+        assert_eq!(grid.tiles(), &flat[..]);
+
+        // row 9 spans 0..10 and column 19 spans 10..20
+        let tile: &Tile = grid.get(9, 19).unwrap();
+        assert_eq!(tile.vertices.exterior().0[0], coord! {x: 10.0, y: 0.0});
+        assert!(!tile.polygons.is_empty());
+        assert!(grid.get(18, 0).is_none() && grid.get(0, 36).is_none());
+
+        let by_coord: &mut Tile = grid.get_mut_by_coord(coord! {x: 10.0, y: 0.0}).unwrap();
+        assert_eq!(by_coord.vertices.exterior().0[0], coord! {x: 10.0, y: 0.0});
+        let north_east: &mut Tile = grid.get_mut_by_coord(coord! {x: 180.0, y: 90.0}).unwrap();
+        assert_eq!(north_east.vertices.exterior().0[0], coord! {x: 170.0, y: 80.0});
+        assert!(grid.get_mut_by_coord(coord! {x: 181.0, y: 0.0}).is_none());
+
+        // a partial grid skips the cells it does not cover
+        let mut partial: Grid = Grid::from_tiles(generate_grid_filtered(10, |tile| {
+            tile.vertices.exterior().0[0].x >= 20.0
+        }).unwrap()).unwrap();
+        assert_eq!(partial.clip(&polygon).unwrap(), 2 * 4);
+        assert!(matches!(Grid::from_tiles(Vec::new()), Err(GeoTilerError::GridGenerationError(_))));
+    }
+
+    #[test]
+    fn test_clip_roundtrip_area_error_is_small() {
+        let polygon: Polygon<f64> = Polygon::new(
+            LineString::from(vec![(-37.3, -21.7), (12.9, -28.1), (41.6, 3.4), (18.2, 33.8), (-29.5, 26.3), (-37.3, -21.7)]),
+            vec![LineString::from(vec![(-5.5, -5.5), (6.5, -5.5), (6.5, 6.5), (-5.5, 6.5), (-5.5, -5.5)])],
+        );
+
+        for step in [1, 10, 20] {
+            let error: f64 = clip_roundtrip_area_error(&polygon, step).unwrap();
+            assert!(error < 1e-7, "area error {} with a {}° grid", error, step);
+        }
+
+        assert!(matches!(clip_roundtrip_area_error(&polygon, 7), Err(GeoTilerError::GridGenerationError(_))));
+    }
+}